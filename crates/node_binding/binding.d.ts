@@ -75,6 +75,7 @@ export class JsCompilation {
   getAssetSource(name: string): JsCompatSource | null
   get modules(): Array<ModuleDTO>
   get builtModules(): Array<ModuleDTO>
+  getExternalModule(request: string, type?: string | undefined | null): ModuleDTO | null
   getOptimizationBailout(): Array<JsStatsOptimizationBailout>
   getChunks(): Array<JsChunk>
   getNamedChunkKeys(): Array<string>
@@ -195,6 +196,8 @@ export class ModuleDto {
   get type(): string
   get layer(): string | undefined
   get blocks(): Array<DependenciesBlockDto>
+  get externalType(): string | undefined
+  get externalRequest(): string | string[] | Record<string, string | string[]> | Array<[string, string | string[]]> | undefined
   size(ty?: string | undefined | null): number
 }
 export type ModuleDTO = ModuleDto
@@ -308,6 +311,9 @@ export enum BuiltinPluginName {
   LightningCssMinimizerRspackPlugin = 'LightningCssMinimizerRspackPlugin',
   BundlerInfoRspackPlugin = 'BundlerInfoRspackPlugin',
   CssExtractRspackPlugin = 'CssExtractRspackPlugin',
+  ExternalExportsValidatorRspackPlugin = 'ExternalExportsValidatorRspackPlugin',
+  MockExternalsRspackPlugin = 'MockExternalsRspackPlugin',
+  TypeScriptPathsExternalsRspackPlugin = 'TypeScriptPathsExternalsRspackPlugin',
   JsLoaderRspackPlugin = 'JsLoaderRspackPlugin',
   LazyCompilationPlugin = 'LazyCompilationPlugin'
 }
@@ -316,6 +322,7 @@ export function cleanupGlobalTrace(): void
 
 export interface ContextInfo {
   issuer: string
+  issuerLayer?: string
 }
 
 export function formatDiagnostic(diagnostic: JsDiagnostic): ExternalObject<'Diagnostic'>
@@ -930,6 +937,8 @@ export interface JsStatsModuleCommonAttributes {
   type: string
   moduleType: string
   layer?: string
+  externalType?: string
+  request?: string
   size: number
   sizes: Array<JsStatsSize>
   built: boolean
@@ -1300,6 +1309,7 @@ export interface RawExperiments {
   topLevelAwait: boolean
   incremental?: RawIncremental
   rspackFuture: RawRspackFuture
+  externals: RawExternalsExperiments
 }
 
 export interface RawExposeOptions {
@@ -1313,6 +1323,7 @@ export interface RawExternalItemFnCtx {
   context: string
   dependencyType: string
   contextInfo: ContextInfo
+  resolveData: RawExternalItemResolveData
 }
 
 export interface RawExternalItemFnResult {
@@ -1320,9 +1331,28 @@ export interface RawExternalItemFnResult {
   result?: string | boolean | string[] | Record<string, string[]>
 }
 
+export interface RawExternalItemResolveData {
+  request: string
+  context: string
+  dependencyType: string
+  contextInfo: ContextInfo
+  path: string
+  query: string
+  fragment: string
+}
+
+export interface RawExternalsExperiments {
+  typeInference: boolean
+  integrity: Record<string, string>
+  runtime: Record<string, Array<string>>
+  expectedVersion: Record<string, string>
+  cspSafe: boolean
+}
+
 export interface RawExternalsPluginOptions {
   type: string
   externals: (string | RegExp | Record<string, string | boolean | string[] | Record<string, string[]>> | ((...args: any[]) => any))[]
+  requestTransformer?: (request: string) => string
 }
 
 export interface RawExternalsPresets {
@@ -1519,6 +1549,11 @@ export interface RawLimitChunkCountPluginOptions {
   maxChunks: number
 }
 
+export interface RawMockExternalsPluginOptions {
+  path: string
+  enabled: boolean
+}
+
 export interface RawModuleArg {
   module: string
   path: string
@@ -1620,6 +1655,7 @@ export interface RawOptimizationOptions {
   innerGraph: boolean
   mangleExports: string
   concatenateModules: boolean
+  minifyExternals: boolean
 }
 
 export interface RawOptions {
@@ -1639,6 +1675,8 @@ export interface RawOptions {
   node?: RawNodeOption
   profile: boolean
   bail: boolean
+  perChunkExternalOverrides: Record<string, Record<string, string>>
+  validateSubpathExports: boolean
   __references: Record<string, any>
 }
 
@@ -1909,6 +1947,10 @@ export interface RawTrustedTypes {
   policyName?: string
 }
 
+export interface RawTypeScriptPathsExternalsPluginOptions {
+  configFile: string
+}
+
 /**
  * Some code is modified based on
  * https://github.com/swc-project/swc/blob/d1d0607158ab40463d1b123fed52cc526eba8385/bindings/binding_core_node/src/util.rs#L29-L58
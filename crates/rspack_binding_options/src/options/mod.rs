@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use napi_derive::napi;
 use rspack_core::{
   unaffected_cache::IncrementalPasses, CacheOptions, CompilerOptions, Context, Experiments,
@@ -55,6 +57,8 @@ pub struct RawOptions {
   pub node: Option<RawNodeOption>,
   pub profile: bool,
   pub bail: bool,
+  pub per_chunk_external_overrides: HashMap<String, HashMap<String, String>>,
+  pub validate_subpath_exports: bool,
   #[napi(js_name = "__references", ts_type = "Record<string, any>")]
   pub __references: References,
 }
@@ -105,6 +109,7 @@ impl TryFrom<RawOptions> for CompilerOptions {
       layers: value.experiments.layers,
       top_level_await: value.experiments.top_level_await,
       rspack_future: value.experiments.rspack_future.into(),
+      externals: value.experiments.externals.into(),
     };
     let optimization = value.optimization.try_into()?;
     let stats = value.stats.into();
@@ -126,6 +131,12 @@ impl TryFrom<RawOptions> for CompilerOptions {
       node,
       profile: value.profile,
       bail: value.bail,
+      per_chunk_external_overrides: value
+        .per_chunk_external_overrides
+        .into_iter()
+        .map(|(chunk, overrides)| (chunk, overrides.into_iter().collect()))
+        .collect(),
+      validate_subpath_exports: value.validate_subpath_exports,
       __references: value.__references,
     })
   }
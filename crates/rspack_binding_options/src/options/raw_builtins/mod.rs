@@ -8,10 +8,14 @@ mod raw_lazy_compilation;
 mod raw_lightning_css_minimizer;
 mod raw_limit_chunk_count;
 mod raw_mf;
+mod raw_mock_externals;
 mod raw_progress;
 mod raw_runtime_chunk;
 mod raw_size_limits;
 mod raw_swc_js_minimizer;
+mod raw_typescript_paths_externals;
+
+use std::path::Path;
 
 use napi::{bindgen_prelude::FromNapiValue, Env, JsUnknown};
 use napi_derive::napi;
@@ -38,7 +42,9 @@ use rspack_plugin_dynamic_entry::DynamicEntryPlugin;
 use rspack_plugin_ensure_chunk_conditions::EnsureChunkConditionsPlugin;
 use rspack_plugin_entry::EntryPlugin;
 use rspack_plugin_externals::{
-  electron_target_plugin, http_externals_rspack_plugin, node_target_plugin, ExternalsPlugin,
+  electron_target_plugin, http_externals_rspack_plugin, node_target_plugin,
+  typescript_paths_externals_plugin, ExternalExportsValidatorPlugin, ExternalRequestTransformer,
+  ExternalsPlugin, MockExternalsPlugin,
 };
 use rspack_plugin_hmr::HotModuleReplacementPlugin;
 use rspack_plugin_html::HtmlRspackPlugin;
@@ -88,8 +94,10 @@ use self::{
   raw_css_extract::RawCssExtractPluginOption,
   raw_lazy_compilation::{JsBackend, RawLazyCompilationOption},
   raw_mf::{RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions, RawProvideOptions},
+  raw_mock_externals::RawMockExternalsPluginOptions,
   raw_runtime_chunk::RawRuntimeChunkOptions,
   raw_size_limits::RawSizeLimitsPluginOptions,
+  raw_typescript_paths_externals::RawTypeScriptPathsExternalsPluginOptions,
 };
 use crate::{
   plugins::JsLoaderRspackPlugin, JsLoaderRunner, RawContextReplacementPluginOptions,
@@ -174,6 +182,9 @@ pub enum BuiltinPluginName {
   LightningCssMinimizerRspackPlugin,
   BundlerInfoRspackPlugin,
   CssExtractRspackPlugin,
+  ExternalExportsValidatorRspackPlugin,
+  MockExternalsRspackPlugin,
+  TypeScriptPathsExternalsRspackPlugin,
 
   // rspack js adapter plugins
   // naming format follow XxxRspackPlugin
@@ -239,7 +250,19 @@ impl BuiltinPlugin {
           .into_iter()
           .map(|e| RawExternalItemWrapper(e).try_into())
           .collect::<Result<Vec<_>>>()?;
-        let plugin = ExternalsPlugin::new(plugin_options.r#type, externals).boxed();
+        let plugin = match plugin_options.request_transformer {
+          Some(request_transformer) => ExternalsPlugin::new_with_request_transformer(
+            plugin_options.r#type,
+            externals,
+            ExternalRequestTransformer::new(move |request| {
+              request_transformer
+                .blocking_call_with_sync(request.to_string())
+                .expect("run ExternalsPlugin request transformer error")
+            }),
+          ),
+          None => ExternalsPlugin::new(plugin_options.r#type, externals),
+        }
+        .boxed();
         plugins.push(plugin);
       }
       BuiltinPluginName::NodeTargetPlugin => plugins.push(node_target_plugin()),
@@ -487,6 +510,21 @@ impl BuiltinPlugin {
         .boxed();
         plugins.push(plugin);
       }
+      BuiltinPluginName::ExternalExportsValidatorRspackPlugin => {
+        plugins.push(ExternalExportsValidatorPlugin::default().boxed())
+      }
+      BuiltinPluginName::MockExternalsRspackPlugin => {
+        let options = downcast_into::<RawMockExternalsPluginOptions>(self.options)?;
+        plugins.push(
+          MockExternalsPlugin::from_json_file(Path::new(&options.path), options.enabled)?.boxed(),
+        )
+      }
+      BuiltinPluginName::TypeScriptPathsExternalsRspackPlugin => {
+        let options = downcast_into::<RawTypeScriptPathsExternalsPluginOptions>(self.options)?;
+        plugins.push(typescript_paths_externals_plugin(Path::new(
+          &options.config_file,
+        ))?)
+      }
       BuiltinPluginName::JsLoaderRspackPlugin => {
         plugins
           .push(JsLoaderRspackPlugin::new(downcast_into::<JsLoaderRunner>(self.options)?).boxed());
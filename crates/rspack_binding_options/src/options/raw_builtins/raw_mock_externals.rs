@@ -0,0 +1,8 @@
+use napi_derive::napi;
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawMockExternalsPluginOptions {
+  pub path: String,
+  pub enabled: bool,
+}
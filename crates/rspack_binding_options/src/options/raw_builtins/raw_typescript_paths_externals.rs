@@ -0,0 +1,7 @@
+use napi_derive::napi;
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawTypeScriptPathsExternalsPluginOptions {
+  pub config_file: String,
+}
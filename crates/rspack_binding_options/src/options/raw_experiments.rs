@@ -1,5 +1,7 @@
+use std::collections::HashMap;
+
 use napi_derive::napi;
-use rspack_core::RspackFuture;
+use rspack_core::{ExternalsExperiments, RspackFuture};
 
 #[derive(Debug, Default)]
 #[napi(object)]
@@ -8,6 +10,7 @@ pub struct RawExperiments {
   pub top_level_await: bool,
   pub incremental: Option<RawIncremental>,
   pub rspack_future: RawRspackFuture,
+  pub externals: RawExternalsExperiments,
 }
 
 #[derive(Debug, Default)]
@@ -33,3 +36,25 @@ impl From<RawRspackFuture> for RspackFuture {
     Self {}
   }
 }
+
+#[derive(Debug, Default)]
+#[napi(object)]
+pub struct RawExternalsExperiments {
+  pub type_inference: bool,
+  pub integrity: HashMap<String, String>,
+  pub runtime: HashMap<String, Vec<String>>,
+  pub expected_version: HashMap<String, String>,
+  pub csp_safe: bool,
+}
+
+impl From<RawExternalsExperiments> for ExternalsExperiments {
+  fn from(value: RawExternalsExperiments) -> Self {
+    Self {
+      type_inference: value.type_inference,
+      integrity: value.integrity.into_iter().collect(),
+      runtime: value.runtime.into_iter().collect(),
+      expected_version: value.expected_version.into_iter().collect(),
+      csp_safe: value.csp_safe,
+    }
+  }
+}
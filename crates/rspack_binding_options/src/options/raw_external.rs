@@ -3,8 +3,8 @@ use std::fmt::Debug;
 
 use napi::bindgen_prelude::Either4;
 use napi_derive::napi;
-use rspack_core::ExternalItemFnCtx;
 use rspack_core::{ExternalItem, ExternalItemFnResult, ExternalItemValue};
+use rspack_core::{ExternalItemFnCtx, ExternalItemResolveData};
 use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
 
@@ -21,6 +21,8 @@ pub struct RawExternalsPluginOptions {
     ts_type = "(string | RegExp | Record<string, string | boolean | string[] | Record<string, string[]>> | ((...args: any[]) => any))[]"
   )]
   pub externals: Vec<RawExternalItem>,
+  #[napi(ts_type = "(request: string) => string")]
+  pub request_transformer: Option<ThreadsafeFunction<String, String>>,
 }
 
 type RawExternalItem = Either4<
@@ -66,6 +68,42 @@ impl From<RawExternalItemFnResult> for ExternalItemFnResult {
 #[napi(object)]
 pub struct ContextInfo {
   pub issuer: String,
+  pub issuer_layer: Option<String>,
+}
+
+impl From<rspack_core::ContextInfo> for ContextInfo {
+  fn from(value: rspack_core::ContextInfo) -> Self {
+    Self {
+      issuer: value.issuer,
+      issuer_layer: value.issuer_layer,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct RawExternalItemResolveData {
+  pub request: String,
+  pub context: String,
+  pub dependency_type: String,
+  pub context_info: ContextInfo,
+  pub path: String,
+  pub query: String,
+  pub fragment: String,
+}
+
+impl From<ExternalItemResolveData> for RawExternalItemResolveData {
+  fn from(value: ExternalItemResolveData) -> Self {
+    Self {
+      request: value.request,
+      context: value.context,
+      dependency_type: value.dependency_type,
+      context_info: value.context_info.into(),
+      path: value.path,
+      query: value.query,
+      fragment: value.fragment,
+    }
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -75,6 +113,7 @@ pub struct RawExternalItemFnCtx {
   pub context: String,
   pub dependency_type: String,
   pub context_info: ContextInfo,
+  pub resolve_data: RawExternalItemResolveData,
 }
 
 impl From<ExternalItemFnCtx> for RawExternalItemFnCtx {
@@ -83,9 +122,8 @@ impl From<ExternalItemFnCtx> for RawExternalItemFnCtx {
       request: value.request,
       dependency_type: value.dependency_type,
       context: value.context,
-      context_info: ContextInfo {
-        issuer: value.context_info.issuer,
-      },
+      context_info: value.context_info.into(),
+      resolve_data: value.resolve_data.into(),
     }
   }
 }
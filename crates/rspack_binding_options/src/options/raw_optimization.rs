@@ -11,6 +11,7 @@ pub struct RawOptimizationOptions {
   pub inner_graph: bool,
   pub mangle_exports: String,
   pub concatenate_modules: bool,
+  pub minify_externals: bool,
 }
 
 impl TryFrom<RawOptimizationOptions> for Optimization {
@@ -25,6 +26,7 @@ impl TryFrom<RawOptimizationOptions> for Optimization {
       inner_graph: value.inner_graph,
       mangle_exports: MangleExportsOption::from(value.mangle_exports.as_str()),
       concatenate_modules: value.concatenate_modules,
+      minify_externals: value.minify_externals,
     })
   }
 }
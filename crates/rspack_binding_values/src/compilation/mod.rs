@@ -158,6 +158,30 @@ impl JsCompilation {
       .collect::<Vec<_>>()
   }
 
+  #[napi(ts_return_type = "ModuleDTO | null")]
+  pub fn get_external_module(
+    &'static self,
+    request: String,
+    r#type: Option<String>,
+  ) -> Option<ModuleDTOWrapper> {
+    self
+      .0
+      .get_module_graph()
+      .modules()
+      .into_iter()
+      .filter(|(_, module)| {
+        module.as_external_module().is_some_and(|external_module| {
+          external_module.user_request == request
+            && r#type
+              .as_ref()
+              .map_or(true, |ty| external_module.get_external_type() == ty)
+        })
+      })
+      .map(|(module_id, _)| module_id)
+      .min()
+      .map(|module_id| ModuleDTOWrapper::new(module_id, self.0))
+  }
+
   #[napi]
   pub fn get_optimization_bailout(&self) -> Vec<JsStatsOptimizationBailout> {
     self
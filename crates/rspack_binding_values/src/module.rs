@@ -166,6 +166,9 @@ impl ModuleDTO {
   #[napi(getter)]
   pub fn user_request(&self) -> Either<&str, ()> {
     let module = self.module();
+    if let Some(external_module) = module.as_external_module() {
+      return Either::A(&external_module.user_request);
+    }
     match module.try_as_normal_module() {
       Ok(normal_module) => Either::A(normal_module.user_request()),
       Err(_) => Either::B(()),
@@ -221,6 +224,29 @@ impl ModuleDTO {
       .collect::<Vec<_>>()
   }
 
+  #[napi(getter)]
+  pub fn external_type(&self) -> Either<&str, ()> {
+    let module = self.module();
+    match module.as_external_module() {
+      Some(external_module) => Either::A(external_module.get_external_type().as_str()),
+      None => Either::B(()),
+    }
+  }
+
+  #[napi(
+    getter,
+    ts_return_type = "string | string[] | Record<string, string | string[]> | Array<[string, string | string[]]> | undefined"
+  )]
+  pub fn external_request(&self) -> Result<Either<serde_json::Value, ()>> {
+    let module = self.module();
+    match module.as_external_module() {
+      Some(external_module) => serde_json::to_value(&external_module.request)
+        .map(Either::A)
+        .map_err(|e| Error::from_reason(e.to_string())),
+      None => Ok(Either::B(())),
+    }
+  }
+
   #[napi]
   pub fn size(&self, ty: Option<String>) -> f64 {
     let module = self.module();
@@ -487,6 +487,8 @@ pub struct JsStatsModuleCommonAttributes {
   pub r#type: &'static str,
   pub module_type: &'static str,
   pub layer: Option<String>,
+  pub external_type: Option<String>,
+  pub request: Option<String>,
   pub size: f64,
   pub sizes: Vec<JsStatsSize>,
   pub built: bool,
@@ -656,6 +658,8 @@ impl TryFrom<StatsModule<'_>> for JsStatsModule {
       r#type: stats.r#type,
       module_type: stats.module_type.as_str(),
       layer: stats.layer.map(|i| i.into_owned()),
+      external_type: stats.external_type.map(|i| i.to_string()),
+      request: stats.external_request.map(|i| i.to_string()),
       size: stats.size,
       sizes,
       built: stats.built,
@@ -3,7 +3,7 @@ use std::{
   fmt::Debug,
   hash::{BuildHasherDefault, Hash},
   path::PathBuf,
-  sync::{atomic::AtomicU32, Arc},
+  sync::{atomic::AtomicU32, Arc, OnceLock},
 };
 
 use dashmap::DashSet;
@@ -41,9 +41,9 @@ use crate::{
   ChunkGraph, ChunkGroupByUkey, ChunkGroupUkey, ChunkKind, ChunkUkey, CodeGenerationJob,
   CodeGenerationResult, CodeGenerationResults, CompilationLogger, CompilationLogging,
   CompilerOptions, DependencyId, DependencyType, Entry, EntryData, EntryOptions, EntryRuntime,
-  Entrypoint, ExecuteModuleId, Filename, ImportVarMap, LocalFilenameFn, Logger, ModuleFactory,
-  ModuleGraph, ModuleGraphPartial, ModuleIdentifier, PathData, ResolverFactory, RuntimeGlobals,
-  RuntimeModule, RuntimeSpecMap, SharedPluginDriver, SourceType, Stats,
+  Entrypoint, ExecuteModuleId, ExternalModule, Filename, ImportVarMap, LocalFilenameFn, Logger,
+  ModuleFactory, ModuleGraph, ModuleGraphPartial, ModuleIdentifier, PathData, ResolverFactory,
+  RuntimeGlobals, RuntimeModule, RuntimeSpecMap, SharedPluginDriver, SourceType, Stats,
 };
 
 pub type BuildDependency = (
@@ -53,6 +53,8 @@ pub type BuildDependency = (
 
 define_hook!(CompilationAddEntry: AsyncSeries(compilation: &mut Compilation, entry_name: Option<&str>));
 define_hook!(CompilationBuildModule: AsyncSeries(module: &mut BoxModule));
+// Called before an external module is built, taps may set `ExternalModule::async_init`
+define_hook!(CompilationBeforeExternalModuleInit: AsyncSeries(module: &mut ExternalModule));
 define_hook!(CompilationStillValidModule: AsyncSeries(module: &mut BoxModule));
 define_hook!(CompilationSucceedModule: AsyncSeries(module: &mut BoxModule));
 define_hook!(CompilationExecuteModule:
@@ -85,6 +87,7 @@ define_hook!(CompilationAfterSeal: AsyncSeries(compilation: &mut Compilation));
 pub struct CompilationHooks {
   pub add_entry: CompilationAddEntryHook,
   pub build_module: CompilationBuildModuleHook,
+  pub before_external_module_init: CompilationBeforeExternalModuleInitHook,
   pub still_valid_module: CompilationStillValidModuleHook,
   pub succeed_module: CompilationSucceedModuleHook,
   pub execute_module: CompilationExecuteModuleHook,
@@ -130,6 +133,35 @@ impl Default for CompilationId {
 
 type ValueCacheVersions = HashMap<String, String>;
 
+/// Runtime requirements of externals with the runtime module implementing them, its name in
+/// diagnostics and a hint at the plugin that adds it, if it is not always added by the runtime.
+const EXTERNAL_RUNTIME_MODULES: &[(RuntimeGlobals, &str, &str, Option<&str>)] = &[
+  (
+    RuntimeGlobals::DEFINE_PROPERTY_GETTERS,
+    "webpack/runtime/define_property_getters",
+    "the define property getters runtime",
+    None,
+  ),
+  (
+    RuntimeGlobals::LOAD_SCRIPT,
+    "webpack/runtime/load_script",
+    "the load script runtime",
+    None,
+  ),
+  (
+    RuntimeGlobals::FEDERATION,
+    "module_federation/runtime",
+    "the Module Federation runtime",
+    Some("Add ModuleFederationPlugin to the configuration."),
+  ),
+  (
+    RuntimeGlobals::SHARE_SCOPE_MAP,
+    "webpack/runtime/sharing",
+    "the share scope runtime",
+    Some("Add ModuleFederationPlugin with shared modules to the configuration."),
+  ),
+];
+
 static COMPILATION_ID: AtomicU32 = AtomicU32::new(0);
 #[derive(Derivative)]
 #[derivative(Debug)]
@@ -191,6 +223,8 @@ pub struct Compilation {
   pub value_cache_versions: ValueCacheVersions,
 
   import_var_map: IdentifierDashMap<ImportVarMap>,
+  /// Sorted module ids of all external modules, collected once module ids are assigned.
+  external_module_ids: OnceLock<Vec<String>>,
 
   pub module_executor: Option<ModuleExecutor>,
 
@@ -289,6 +323,7 @@ impl Compilation {
       value_cache_versions: ValueCacheVersions::default(),
 
       import_var_map: IdentifierDashMap::default(),
+      external_module_ids: OnceLock::new(),
 
       module_executor,
 
@@ -482,6 +517,33 @@ impl Compilation {
     import_var
   }
 
+  /// Returns the position of `module_id` among the module ids of all external modules. The ids
+  /// are collected once, so it must not be called before module ids are assigned.
+  pub fn get_external_module_id_index(&self, module_id: &str) -> usize {
+    let external_module_ids = self.external_module_ids.get_or_init(|| {
+      let module_graph = self.get_module_graph();
+      let mut external_module_ids = module_graph
+        .modules()
+        .into_iter()
+        .filter(|(_, module)| module.as_external_module().is_some())
+        .filter_map(|(identifier, _)| {
+          self
+            .chunk_graph
+            .chunk_graph_module_by_module_identifier
+            .get(&identifier)
+            .and_then(|cgm| cgm.id.as_deref())
+            .map(ToString::to_string)
+        })
+        .collect::<Vec<_>>();
+      external_module_ids.sort_unstable();
+      external_module_ids.dedup();
+      external_module_ids
+    });
+    external_module_ids
+      .binary_search_by(|id| id.as_str().cmp(module_id))
+      .unwrap_or_else(|index| index)
+  }
+
   pub async fn add_entry(&mut self, entry: BoxDependency, options: EntryOptions) -> Result<()> {
     let entry_id = *entry.id();
     let entry_name = options.name.clone();
@@ -1194,6 +1256,9 @@ impl Compilation {
     ) {}
     logger.time_end(start);
 
+    self.link_entry_imports();
+    self.validate_csp_safe_externals();
+
     let start = logger.time("create chunks");
     use_code_splitting_cache(self, |compilation| async {
       build_chunk_graph(compilation)?;
@@ -1323,6 +1388,7 @@ impl Compilation {
         plugin_driver.clone(),
       )
       .await?;
+    self.validate_external_runtime_requirements();
     logger.time_end(start);
 
     let start = logger.time("hashing");
@@ -1586,6 +1652,216 @@ impl Compilation {
     Ok(())
   }
 
+  /// Makes an entry that loads another entry through an `entry-import` external depend on it,
+  /// as if `dependOn` was specified, so the imported entry is loaded first and shares its
+  /// runtime. Entries that import each other are reported as errors.
+  fn link_entry_imports(&mut self) {
+    let module_graph = self.get_module_graph();
+    let entry_imports = module_graph
+      .modules()
+      .values()
+      .filter_map(|module| {
+        let entry = module.as_external_module()?.get_entry_import()?;
+        Some((module.identifier(), entry.to_string()))
+      })
+      .collect::<IdentifierMap<_>>();
+    if entry_imports.is_empty() {
+      return;
+    }
+
+    let mut diagnostics = vec![];
+    let mut imports: IndexMap<String, Vec<String>> = IndexMap::default();
+    for (name, entry_data) in &self.entries {
+      let mut visited = IdentifierSet::default();
+      let mut queue = entry_data
+        .dependencies
+        .iter()
+        .filter_map(|dependency| module_graph.module_identifier_by_dependency_id(dependency))
+        .copied()
+        .collect::<Vec<_>>();
+      let mut targets = vec![];
+      while let Some(module) = queue.pop() {
+        if !visited.insert(module) {
+          continue;
+        }
+        if let Some(target) = entry_imports.get(&module) {
+          if !targets.contains(target) {
+            targets.push(target.clone());
+          }
+          continue;
+        }
+        queue.extend(
+          module_graph
+            .get_outgoing_connections(&module)
+            .into_iter()
+            .map(|connection| *connection.module_identifier()),
+        );
+      }
+      targets.sort();
+      for target in targets {
+        if !self.entries.contains_key(&target) {
+          diagnostics.push(Diagnostic::error(
+            "Unknown entry import".into(),
+            format!("Entrypoint '{name}' imports entry '{target}', which does not exist."),
+          ));
+        } else if entry_data.options.runtime.is_some() {
+          diagnostics.push(Diagnostic::error(
+            "Invalid entry import".into(),
+            format!(
+              "Entrypoint '{name}' imports entry '{target}' and has 'runtime' specified. Entrypoints that import other entrypoints use their runtime instead, remove the 'runtime' option from the entrypoint."
+            ),
+          ));
+        } else {
+          imports.entry(name.clone()).or_default().push(target);
+        }
+      }
+    }
+
+    // Edges are linked one by one, so of two entries importing each other only the
+    // edge closing the cycle is reported.
+    let mut linked = IndexMap::<String, Vec<String>>::default();
+    for (name, targets) in imports {
+      for target in targets {
+        let mut visited = HashSet::default();
+        let mut queue = vec![target.clone()];
+        let mut circular = false;
+        while let Some(entry) = queue.pop() {
+          if entry == name {
+            circular = true;
+            break;
+          }
+          if !visited.insert(entry.clone()) {
+            continue;
+          }
+          if let Some(depend_on) = self
+            .entries
+            .get(&entry)
+            .and_then(|entry| entry.options.depend_on.as_ref())
+          {
+            queue.extend(depend_on.iter().cloned());
+          }
+          if let Some(targets) = linked.get(&entry) {
+            queue.extend(targets.iter().cloned());
+          }
+        }
+        if circular {
+          diagnostics.push(Diagnostic::error(
+            "Circular entry import".into(),
+            format!(
+              "Entrypoints '{name}' and '{target}' import each other in a circular way, through 'entry-import' externals or 'dependOn'."
+            ),
+          ));
+        } else {
+          linked.entry(name.clone()).or_default().push(target);
+        }
+      }
+    }
+
+    for (name, targets) in linked {
+      let Some(entry_data) = self.entries.get_mut(&name) else {
+        continue;
+      };
+      let depend_on = entry_data.options.depend_on.get_or_insert_with(Vec::new);
+      for target in targets {
+        if !depend_on.contains(&target) {
+          depend_on.push(target);
+        }
+      }
+    }
+    self.extend_diagnostics(diagnostics);
+  }
+
+  /// Warns about `csp_safe` externals that are imported unconditionally, as they evaluate to
+  /// `undefined` when the global is missing instead of failing where they are loaded.
+  fn validate_csp_safe_externals(&mut self) {
+    let module_graph = self.get_module_graph();
+    let mut diagnostics = module_graph
+      .modules()
+      .values()
+      .filter_map(|module| module.as_external_module())
+      .filter(|external_module| {
+        external_module.csp_safe
+          && matches!(external_module.get_external_type().as_str(), "window" | "self")
+          && !module_graph.is_optional(&external_module.identifier())
+      })
+      .map(|external_module| {
+        (
+          external_module.identifier(),
+          Diagnostic::warn(
+            "CSP safe external".into(),
+            format!(
+              "{} is undefined when the global is missing, but it is not imported optionally, e.g. in a try block.",
+              external_module.describe()
+            ),
+          )
+          .with_module_identifier(Some(external_module.identifier())),
+        )
+      })
+      .collect::<Vec<_>>();
+    diagnostics.sort_by_key(|(identifier, _)| *identifier);
+    self.extend_diagnostics(diagnostics.into_iter().map(|(_, diagnostic)| diagnostic));
+  }
+
+  /// Reports external modules whose runtime requirements, e.g. `__webpack_require__.d` for
+  /// `module` externals, are not provided by the runtime chunk of an entry that loads them, i.e.
+  /// the runtime module that implements the requirement is not attached to it. Plugins only add
+  /// some of these runtime modules, e.g. the Module Federation runtime.
+  fn validate_external_runtime_requirements(&mut self) {
+    let mut diagnostics = vec![];
+    for entry_ukey in self.get_chunk_graph_entries() {
+      let tree_runtime_requirements = self.chunk_graph.get_tree_runtime_requirements(&entry_ukey);
+      let missing = EXTERNAL_RUNTIME_MODULES
+        .iter()
+        .filter(|(requirement, runtime_module, ..)| {
+          tree_runtime_requirements.contains(*requirement)
+            && !self
+              .chunk_graph
+              .get_chunk_runtime_modules_iterable(&entry_ukey)
+              .any(|identifier| identifier.as_str() == *runtime_module)
+        })
+        .collect::<Vec<_>>();
+      if missing.is_empty() {
+        continue;
+      }
+      // only look for the externals that need a missing runtime module when there is one
+      let module_graph = self.get_module_graph();
+      let entry = self.chunk_by_ukey.expect_get(&entry_ukey);
+      for chunk_ukey in entry.get_all_referenced_chunks(&self.chunk_group_by_ukey) {
+        let chunk = self.chunk_by_ukey.expect_get(&chunk_ukey);
+        for module in self
+          .chunk_graph
+          .get_chunk_modules(&chunk_ukey, &module_graph)
+        {
+          let Some(external_module) = module.as_external_module() else {
+            continue;
+          };
+          let Some(runtime_requirements) =
+            ChunkGraph::get_module_runtime_requirements(self, module.identifier(), &chunk.runtime)
+          else {
+            continue;
+          };
+          for (requirement, _, runtime_module_name, hint) in &missing {
+            if !runtime_requirements.contains(*requirement) {
+              continue;
+            }
+            diagnostics.push(Diagnostic::error(
+              "Missing runtime module".into(),
+              format!(
+                "{} requires {}, but the runtime of entry chunk {} does not include {}.{}",
+                external_module.describe(),
+                requirement,
+                entry.name.as_deref().unwrap_or("(anonymous)"),
+                runtime_module_name,
+                hint.map(|hint| format!(" {hint}")).unwrap_or_default()
+              ),
+            ));
+          }
+        }
+      }
+    }
+    self.extend_diagnostics(diagnostics);
+  }
+
   #[instrument(name = "compilation:create_hash", skip_all)]
   pub async fn create_hash(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
     let logger = self.get_logger("rspack.Compilation");
@@ -49,6 +49,14 @@ impl Task<MakeTaskContext> for BuildTask {
       .call(&mut module)
       .await?;
 
+    if let Some(external_module) = module.as_external_module_mut() {
+      plugin_driver
+        .compilation_hooks
+        .before_external_module_init
+        .call(external_module)
+        .await?;
+    }
+
     let result = module
       .build(
         BuildContext {
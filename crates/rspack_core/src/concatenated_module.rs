@@ -38,13 +38,14 @@ use crate::{
   reserved_names::RESERVED_NAMES, returning_function, runtime_condition_expression,
   subtract_runtime_condition, to_identifier, AsyncDependenciesBlockIdentifier, BoxDependency,
   BuildContext, BuildInfo, BuildMeta, BuildMetaDefaultObject, BuildMetaExportsType, BuildResult,
-  ChunkInitFragments, CodeGenerationDataTopLevelDeclarations, CodeGenerationExportsFinalNames,
-  CodeGenerationResult, Compilation, ConcatenatedModuleIdent, ConcatenationScope, ConnectionState,
-  Context, DependenciesBlock, DependencyId, DependencyTemplate, DependencyType, ErrorSpan,
-  ExportInfo, ExportInfoProvided, ExportsArgument, ExportsType, FactoryMeta, IdentCollector,
-  LibIdentOptions, Module, ModuleDependency, ModuleGraph, ModuleGraphConnection, ModuleIdentifier,
-  ModuleLayer, ModuleType, Resolve, RuntimeCondition, RuntimeGlobals, RuntimeSpec, SourceType,
-  SpanExt, Template, UsageState, UsedName, DEFAULT_EXPORT, NAMESPACE_OBJECT_EXPORT,
+  ChunkInitFragments, ChunkInitFragmentsExt, CodeGenerationDataTopLevelDeclarations,
+  CodeGenerationExportsFinalNames, CodeGenerationResult, Compilation, ConcatenatedModuleIdent,
+  ConcatenationScope, ConnectionState, Context, DependenciesBlock, DependencyId,
+  DependencyTemplate, DependencyType, ErrorSpan, ExportInfo, ExportInfoProvided, ExportsArgument,
+  ExportsType, FactoryMeta, IdentCollector, LibIdentOptions, Module, ModuleDependency, ModuleGraph,
+  ModuleGraphConnection, ModuleIdentifier, ModuleLayer, ModuleType, Resolve, RuntimeCondition,
+  RuntimeGlobals, RuntimeSpec, SourceType, SpanExt, Template, UsageState, UsedName, DEFAULT_EXPORT,
+  NAMESPACE_OBJECT_EXPORT,
 };
 
 type ExportsDefinitionArgs = Vec<(String, String)>;
@@ -1165,7 +1166,7 @@ impl Module for ConcatenatedModule {
       }
     }
 
-    let mut chunk_init_fragments = Vec::new();
+    let mut chunk_init_fragments = ChunkInitFragments::default();
 
     // Evaluate modules in order
     let module_graph = compilation.get_module_graph();
@@ -1193,7 +1194,7 @@ impl Module for ConcatenatedModule {
           result.add(info.source.clone().expect("should have source"));
 
           for f in info.chunk_init_fragments.iter() {
-            chunk_init_fragments.push(f.clone());
+            chunk_init_fragments.add(f.clone());
           }
 
           runtime_requirements = runtime_requirements.union(info.runtime_requirements);
@@ -1703,7 +1704,7 @@ impl ConcatenatedModule {
       } = codegen_res;
 
       if let Some(fragments) = codegen_res.data.get::<ChunkInitFragments>() {
-        chunk_init_fragments.extend(fragments.iter().cloned());
+        chunk_init_fragments.add_all(fragments.iter().cloned());
       }
 
       let concatenation_scope = concatenation_scope.expect("should have concatenation_scope");
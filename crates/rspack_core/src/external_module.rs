@@ -1,32 +1,117 @@
-use std::{borrow::Cow, iter};
+use std::{
+  borrow::Cow,
+  collections::BTreeMap,
+  hash::{Hash, Hasher},
+  iter,
+  path::Path,
+  sync::Mutex,
+};
 
-use rspack_collections::{Identifiable, Identifier};
-use rspack_error::{error, impl_empty_diagnosable_trait, Diagnostic, Result};
+use rspack_collections::{Identifiable, Identifier, IdentifierSet};
+use rspack_error::{error, Diagnosable, Diagnostic, Result};
+use rspack_fs::ReadableFileSystem;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
 use rspack_macros::impl_source_map_config;
-use rspack_util::{ext::DynHash, json_stringify, source_map::SourceMapKind};
-use rustc_hash::{FxHashMap as HashMap, FxHashSet};
+use rspack_util::{
+  ext::DynHash,
+  json_stringify,
+  source_map::{ModuleSourceMapConfig, SourceMapKind},
+};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet, FxHasher};
 use serde::Serialize;
 
 use crate::{
-  extract_url_and_global, impl_module_meta_info, module_update_hash, property_access,
-  rspack_sources::{BoxSource, RawSource, Source, SourceExt},
-  to_identifier, AsyncDependenciesBlockIdentifier, BuildContext, BuildInfo, BuildMeta,
-  BuildMetaExportsType, BuildResult, ChunkInitFragments, ChunkUkey, CodeGenerationDataUrl,
-  CodeGenerationResult, Compilation, ConcatenationScope, Context, DependenciesBlock, DependencyId,
-  ExternalType, FactoryMeta, InitFragmentExt, InitFragmentKey, InitFragmentStage, LibIdentOptions,
-  Module, ModuleType, NormalInitFragment, RuntimeGlobals, RuntimeSpec, SourceType,
-  StaticExportsDependency, StaticExportsSpec, NAMESPACE_OBJECT_EXPORT,
+  extract_url_and_global, impl_module_meta_info, module_raw, module_update_hash, property_access,
+  rspack_sources::{BoxSource, OriginalSource, RawSource, Source, SourceExt},
+  runtime_condition_expression, to_identifier, AliasMap, AsyncDependenciesBlockIdentifier,
+  BoxChunkInitFragment, BuildContext, BuildInfo, BuildMeta, BuildMetaExportsType, BuildResult,
+  ChunkInitFragments, ChunkInitFragmentsExt, ChunkUkey, CodeGenerationDataUrl,
+  CodeGenerationResult, Compilation, ConcatenationScope, ConnectionState, Context,
+  DependenciesBlock, DependencyId, DependencyType, ExtendedReferencedExport, ExternalType,
+  FactoryMeta, InitFragmentExt, InitFragmentKey, InitFragmentStage, LibIdentOptions, Module,
+  ModuleType, NormalInitFragment, Resolve, RuntimeCondition, RuntimeGlobals, RuntimeSpec,
+  SourceType, StaticExportsDependency, StaticExportsSpec, WasmLoading, NAMESPACE_OBJECT_EXPORT,
 };
 use crate::{ChunkGraph, ModuleGraph};
 
 static EXTERNAL_MODULE_JS_SOURCE_TYPES: &[SourceType] = &[SourceType::JavaScript];
 static EXTERNAL_MODULE_CSS_SOURCE_TYPES: &[SourceType] = &[SourceType::CssImport];
+static EXTERNAL_MODULE_DATA_URL_SOURCE_TYPES: &[SourceType] =
+  &[SourceType::Asset, SourceType::JavaScript];
+static EXTERNAL_MODULE_WASM_SOURCE_TYPES: &[SourceType] =
+  &[SourceType::JavaScript, SourceType::Wasm];
+static EXTERNAL_MODULE_CSS_MODULE_SOURCE_TYPES: &[SourceType] =
+  &[SourceType::JavaScript, SourceType::CssImport];
+// `@property` is not hoisted with the `@import` rules, which have to come first
+static EXTERNAL_MODULE_CSS_PROPERTY_SOURCE_TYPES: &[SourceType] = &[SourceType::Css];
+
+/// Every external type an `ExternalModule` can be created with, including `asset` and
+/// `css-import`, which are only created internally, and the shorthands `cjs` and `cjs2`.
+pub static KNOWN_EXTERNAL_TYPES: &[&str] = &[
+  "amd",
+  "amd-require",
+  "asset",
+  "assign",
+  "bare-specifier",
+  "cjs",
+  "cjs2",
+  "commonjs",
+  "commonjs-module",
+  "commonjs-static",
+  "commonjs2",
+  "const",
+  "css-import",
+  "css-module-import",
+  "css-property",
+  "dynamic-require",
+  "entry-import",
+  "esm-sh",
+  "federation-delegate",
+  "global",
+  "import",
+  "import-css-worklet",
+  "import-lazy",
+  "import-script",
+  "import-sync",
+  "inline-umd",
+  "jsonp",
+  "let",
+  "micro-frontend",
+  "module",
+  "module-import",
+  "node-commonjs",
+  "promise",
+  "remote-entry-v2",
+  "sass-module",
+  "script",
+  "self",
+  "system",
+  "this",
+  "umd",
+  "umd2",
+  "unpkg",
+  "var",
+  "wasm",
+  "window",
+];
+
+/// Maps the deprecated shorthands `cjs` and `cjs2` to the types they stand for.
+pub fn canonical_external_type(external_type: &str) -> Option<&'static str> {
+  match external_type {
+    "cjs" => Some("commonjs"),
+    "cjs2" => Some("commonjs2"),
+    _ => None,
+  }
+}
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
 pub enum ExternalRequest {
   Single(ExternalRequestValue),
   Map(HashMap<String, ExternalRequestValue>),
+  /// Ordered fallbacks, the first one available at runtime is used, e.g.
+  /// `[["commonjs", "lodash"], ["window", "_"]]`.
+  Chain(Vec<(ExternalType, ExternalRequestValue)>),
 }
 
 #[derive(Debug, Clone)]
@@ -68,6 +153,100 @@ impl ExternalRequestValue {
       iter::once(&self.primary).chain(&[])
     }
   }
+
+  fn canonicalize(mut self) -> Self {
+    let canonical = canonicalize_request(&self.primary);
+    if canonical.len() != self.primary.len() {
+      self.primary = canonical.into_owned();
+    }
+    self
+  }
+}
+
+impl ExternalRequest {
+  fn map_primary(self, mut f: impl FnMut(&str) -> String) -> Self {
+    let mut transform = |value: ExternalRequestValue| {
+      let primary = f(value.primary());
+      ExternalRequestValue::new(primary, value.rest)
+    };
+    match self {
+      Self::Single(value) => Self::Single(transform(value)),
+      Self::Map(map) => Self::Map(
+        map
+          .into_iter()
+          .map(|(key, value)| (key, transform(value)))
+          .collect(),
+      ),
+      Self::Chain(chain) => Self::Chain(
+        chain
+          .into_iter()
+          .map(|(external_type, value)| (external_type, transform(value)))
+          .collect(),
+      ),
+    }
+  }
+
+  fn canonicalize(self) -> Self {
+    match self {
+      Self::Single(value) => Self::Single(value.canonicalize()),
+      Self::Map(map) => Self::Map(
+        map
+          .into_iter()
+          .map(|(k, value)| (k, value.canonicalize()))
+          .collect(),
+      ),
+      Self::Chain(chain) => Self::Chain(
+        chain
+          .into_iter()
+          .map(|(external_type, value)| (external_type, value.canonicalize()))
+          .collect(),
+      ),
+    }
+  }
+}
+
+// only the identifier is canonicalized, the generated code loads the request as configured
+fn get_external_identifier(
+  external_type: &str,
+  dependency_meta: &DependencyMeta,
+  request: ExternalRequest,
+) -> Identifier {
+  Identifier::from(format!(
+    "external {} {}",
+    resolve_external_type(external_type, dependency_meta),
+    serde_json::to_string(&request.canonicalize()).expect("invalid json to_string")
+  ))
+}
+
+/// Strip redundant leading `./` from bare-package-like requests, so that
+/// `"./lodash"` and `"lodash"` point to the same external module.
+/// Relative parent paths (`../foo`) and absolute paths are kept as is.
+pub fn canonicalize_request(request: &str) -> Cow<str> {
+  let mut canonical = request;
+  while let Some(rest) = canonical.strip_prefix("./") {
+    canonical = rest;
+  }
+  if canonical.len() != request.len()
+    && canonical.starts_with(|c: char| c.is_ascii_alphanumeric() || matches!(c, '@' | '_' | '$'))
+  {
+    Cow::Borrowed(canonical)
+  } else {
+    Cow::Borrowed(request)
+  }
+}
+
+/// Whether the request is a `data:` URI of an image or another resource that is not a script,
+/// e.g. `data:image/svg+xml,<svg ...>`. Such externals are inlined assets like those of
+/// `url-loader`, JavaScript `data:` URIs can still be imported by `import` externals.
+pub fn is_asset_data_url(request: &str) -> bool {
+  let Some(data) = request.strip_prefix("data:") else {
+    return false;
+  };
+  let Some((media_type, _)) = data.split_once(',') else {
+    return false;
+  };
+  let mime_type = media_type.split(';').next().unwrap_or_default();
+  !mime_type.contains("javascript") && !mime_type.contains("ecmascript")
 }
 
 fn get_namespace_object_export(
@@ -94,12 +273,206 @@ fn get_source_for_global_variable_external(
   format!("{external_type}{object_lookup}")
 }
 
+/// `this` is `undefined` in a strict function, in which case the global object is read instead.
+fn get_source_for_strict_this_external(variable_names: &ExternalRequestValue) -> String {
+  let object_lookup = property_access(variable_names.iter(), 0);
+  format!(
+    "(function() {{ \"use strict\"; return typeof this !== \"undefined\" ? this{object_lookup} : (typeof globalThis !== \"undefined\" ? globalThis : self){object_lookup}; }}())"
+  )
+}
+
+/// Reads the global without throwing when it is missing, e.g. when a strict Content Security
+/// Policy keeps the script defining it from loading, and yields `undefined` instead.
+fn get_source_for_csp_safe_global_variable_external(
+  variable_names: &ExternalRequestValue,
+  external_type: &ExternalType,
+) -> String {
+  format!(
+    "(typeof {external_type} !== \"undefined\" && Object.prototype.hasOwnProperty.call({external_type}, {}) ? {} : undefined)",
+    json_stringify(variable_names.primary()),
+    get_source_for_global_variable_external(variable_names, external_type)
+  )
+}
+
+/// WAT import declarations of the names WebAssembly modules import from the external, declared
+/// as functions as that's what JavaScript modules provide to WebAssembly.
+fn get_source_for_wasm_imports(module: &str, names: &[String]) -> String {
+  names
+    .iter()
+    .map(|name| {
+      format!(
+        "(import {} {} (func))",
+        json_stringify(module),
+        json_stringify(name)
+      )
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// Loads a remote module through the Module Federation runtime, the request is the remote
+/// module id, optionally with the `mf://` protocol, e.g. `mf://app/Button`.
+fn get_source_for_micro_frontend(request: &ExternalRequestValue) -> String {
+  let primary = request.primary();
+  format!(
+    "{}.federation.loadRemote({})",
+    RuntimeGlobals::REQUIRE,
+    json_stringify(primary.strip_prefix("mf://").unwrap_or(primary))
+  )
+}
+
+/// The Levenshtein distance between two strings, the number of single character insertions,
+/// deletions and substitutions that turn one into the other.
+fn edit_distance(a: &str, b: &str) -> usize {
+  let b = b.chars().collect::<Vec<_>>();
+  let mut row = (0..=b.len()).collect::<Vec<_>>();
+  for (i, a) in a.chars().enumerate() {
+    let mut diagonal = row[0];
+    row[0] = i + 1;
+    for (j, b) in b.iter().enumerate() {
+      let substitution = diagonal + usize::from(a != *b);
+      diagonal = row[j + 1];
+      row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+    }
+  }
+  row[b.len()]
+}
+
+/// Reads a remote container from the Module Federation v2 runtime, the request is the name of
+/// the remote, e.g. `app`. Unlike v1 containers, the remote entry is loaded and initialized by
+/// the runtime, so the container is available synchronously.
+fn get_source_for_remote_entry_v2(request: &ExternalRequestValue) -> String {
+  format!(
+    "{}.federation.getRemote({})",
+    RuntimeGlobals::REQUIRE,
+    json_stringify(request.primary())
+  )
+}
+
+/// Defines a custom property with an `@property` rule, the request is the name of the property
+/// followed by its descriptors, e.g. `--angle; syntax: <angle>; inherits: false; initial-value: 0`.
+fn get_source_for_css_property(request: &str) -> Result<String> {
+  let mut parts = request
+    .split(';')
+    .map(str::trim)
+    .filter(|part| !part.is_empty());
+  let name = parts
+    .next()
+    .filter(|name| name.len() > 2 && name.starts_with("--"))
+    .ok_or_else(|| {
+      error!("Invalid request \"{request}\" of css-property external, expected \"--<name>; <descriptor>: <value>\"")
+    })?;
+  let mut descriptors = vec![];
+  for part in parts {
+    let (descriptor, value) = part
+      .split_once(':')
+      .map(|(descriptor, value)| (descriptor.trim(), value.trim()))
+      .filter(|(descriptor, value)| {
+        matches!(*descriptor, "syntax" | "inherits" | "initial-value") && !value.is_empty()
+      })
+      .ok_or_else(|| {
+        error!("Invalid descriptor \"{part}\" of css-property external \"{name}\", expected \"syntax\", \"inherits\" or \"initial-value\"")
+      })?;
+    descriptors.push((descriptor, value));
+  }
+  let descriptor = |name: &str| {
+    descriptors
+      .iter()
+      .find(|(descriptor, _)| *descriptor == name)
+      .map(|(_, value)| *value)
+  };
+  let syntax = descriptor("syntax")
+    .map(|syntax| syntax.trim_matches(['"', '\'']))
+    .ok_or_else(|| error!("Missing \"syntax\" descriptor of css-property external \"{name}\""))?;
+  let inherits = descriptor("inherits")
+    .ok_or_else(|| error!("Missing \"inherits\" descriptor of css-property external \"{name}\""))?;
+  let initial_value = match descriptor("initial-value") {
+    Some(initial_value) => format!("\n  initial-value: {initial_value};"),
+    // only the universal syntax allows to omit the initial value
+    None if syntax == "*" => String::new(),
+    None => {
+      return Err(error!(
+        "Missing \"initial-value\" descriptor of css-property external \"{name}\""
+      ))
+    }
+  };
+  Ok(format!(
+    "@property {name} {{\n  syntax: {};\n  inherits: {inherits};{initial_value}\n}}",
+    json_stringify(syntax)
+  ))
+}
+
+/// Evaluates the UMD source of an `inline-umd` external, the request is the source itself. The
+/// wrapper provides `module` and `exports` and hides `define`, so that the UMD takes its CommonJS
+/// branch, and evaluates to what the UMD exports.
+fn get_source_for_inline_umd(source: &str) -> String {
+  format!(
+    "(function() {{\n\tvar module = {{ exports: {{}} }}, exports = module.exports, define = undefined;\n{source}\n\treturn module.exports;\n}})()"
+  )
+}
+
+/// Reads an eager shared module from the Module Federation share scope, the request is the
+/// share key, optionally prefixed with the share scope, e.g. `react` or `legacy:react`.
+/// Evaluates to `undefined` when no eager version of the module is registered in the scope,
+/// other versions are only available asynchronously.
+fn get_source_for_federation_delegate(request: &ExternalRequestValue) -> String {
+  let (scope, key) = request
+    .primary()
+    .split_once(':')
+    .unwrap_or(("default", request.primary()));
+  let scope = json_stringify(scope);
+  format!(
+    r#"(function() {{
+	if ({initialize_sharing}) {initialize_sharing}({scope});
+	var versions = {share_scope_map} && {share_scope_map}[{scope}] && {share_scope_map}[{scope}][{key}];
+	var version = versions && Object.keys(versions).find(function(version) {{ return versions[version].eager; }});
+	if (!version) return undefined;
+	versions[version].loaded = 1;
+	return versions[version].get()();
+}})()"#,
+    initialize_sharing = RuntimeGlobals::INITIALIZE_SHARING,
+    share_scope_map = RuntimeGlobals::SHARE_SCOPE_MAP,
+    key = json_stringify(key),
+  )
+}
+
+/// The code DefinePlugin, and so EnvironmentPlugin, defines for a `process.env.X` request,
+/// read from the value versions DefinePlugin records for each definition.
+fn get_defined_process_env(
+  compilation: &Compilation,
+  request: &ExternalRequestValue,
+) -> Option<String> {
+  let name = request.primary();
+  if !name.starts_with("process.env.") {
+    return None;
+  }
+  compilation
+    .value_cache_versions
+    .get(&format!("webpack/DefinePlugin {name}"))
+    .and_then(|value| defined_value_to_code(value))
+}
+
+/// Definitions are recorded as JSON, strings are code as is. Objects and arrays are left to the
+/// global lookup.
+fn defined_value_to_code(value: &str) -> Option<String> {
+  match serde_json::from_str(value).ok()? {
+    serde_json::Value::String(code) => Some(code),
+    value @ (serde_json::Value::Bool(_)
+    | serde_json::Value::Number(_)
+    | serde_json::Value::Null) => Some(value.to_string()),
+    _ => None,
+  }
+}
+
 fn get_source_for_default_case(_optional: bool, request: &ExternalRequestValue) -> String {
   let variable_name = request.primary();
   let object_lookup = property_access(request.iter(), 1);
   format!("{variable_name}{object_lookup}")
 }
 
+/// The default import interop (`module.exports.default` when `__esModule` is set) is not
+/// applied here, commonjs externals are built with `BuildMetaExportsType::Dynamic`, so the
+/// importer resolves the default export through `__webpack_require__.n`.
 fn get_source_for_commonjs(module_and_specifiers: &ExternalRequestValue) -> String {
   let module_name = module_and_specifiers.primary();
   format!(
@@ -109,6 +482,132 @@ fn get_source_for_commonjs(module_and_specifiers: &ExternalRequestValue) -> Stri
   )
 }
 
+/// Wraps `module.exports` of a module not flagged with `__esModule` as the default export, the
+/// external is then built as a namespace and named imports only resolve for flagged modules.
+fn get_source_for_commonjs_with_esmodule_interop(
+  module_and_specifiers: &ExternalRequestValue,
+) -> String {
+  format!(
+    "(function(m) {{ return m && m.__esModule ? m : {{ default: m }}; }}({}))",
+    get_source_for_commonjs(module_and_specifiers)
+  )
+}
+
+/// Picks the first link of the chain that is available at runtime, e.g.
+/// `typeof require !== "undefined" ? require("lodash") : window._`. The last link is the
+/// final fallback and is used without a check.
+fn get_source_for_fallback_chain(
+  chain: &[(ExternalType, ExternalRequestValue)],
+  global_object: &ExternalType,
+) -> Result<String> {
+  let mut links = Vec::with_capacity(chain.len());
+  for (external_type, request) in chain {
+    let link = match external_type.as_str() {
+      "commonjs" | "commonjs2" | "commonjs-module" | "commonjs-static" | "node-commonjs" => (
+        "typeof require !== \"undefined\"".to_string(),
+        get_source_for_commonjs(request),
+      ),
+      "window" | "self" => (
+        format!("typeof {external_type} !== \"undefined\""),
+        get_source_for_global_variable_external(request, external_type),
+      ),
+      "global" => (
+        format!("typeof {global_object} !== \"undefined\""),
+        get_source_for_global_variable_external(request, global_object),
+      ),
+      "var" => (
+        format!("typeof {} !== \"undefined\"", request.primary()),
+        get_source_for_default_case(false, request),
+      ),
+      _ => {
+        return Err(error!(
+          "External type \"{external_type}\" can't be used in a fallback chain, only commonjs, node-commonjs, var, window, self and global are supported"
+        ))
+      }
+    };
+    links.push(link);
+  }
+  let Some(((_, fallback), rest)) = links.split_last() else {
+    return Err(error!("External fallback chain must not be empty"));
+  };
+  Ok(
+    rest
+      .iter()
+      .rev()
+      .fold(fallback.clone(), |source, (condition, expression)| {
+        format!("{condition} ? {expression} : {source}")
+      }),
+  )
+}
+
+/// Runs the stub in an async function after `init`, the stub assigns to the shadowed `module`,
+/// so the external resolves to its exports once `init` has settled.
+fn get_source_with_async_init(init: &str, stub: &str) -> String {
+  format!(
+    "module.exports = (async function(module) {{\n{init}\n{stub}\nreturn module.exports;\n}})({{ exports: {{}} }});"
+  )
+}
+
+/// Matches a subpath such as `./sub` against the keys of an `exports` field, including
+/// `./features/*` patterns and legacy `./folder/` keys. A `null` target excludes the subpath.
+fn is_subpath_exported(exports: &serde_json::Value, subpath: &str) -> bool {
+  let Some(map) = exports
+    .as_object()
+    .filter(|map| map.keys().any(|key| key.starts_with('.')))
+  else {
+    // a string, an array or a conditions object only export the main entry
+    return false;
+  };
+  let target = map.get(subpath).or_else(|| {
+    map
+      .iter()
+      .filter(|(key, _)| {
+        if let Some((prefix, suffix)) = key.split_once('*') {
+          subpath.len() >= prefix.len() + suffix.len()
+            && subpath.starts_with(prefix)
+            && subpath.ends_with(suffix)
+        } else {
+          key.ends_with('/') && subpath.starts_with(key.as_str())
+        }
+      })
+      .max_by_key(|(key, _)| key.len())
+      .map(|(_, target)| target)
+  });
+  target.is_some_and(|target| !target.is_null())
+}
+
+fn get_create_require_init_fragment() -> BoxChunkInitFragment {
+  NormalInitFragment::new(
+    "import { createRequire as __WEBPACK_EXTERNAL_createRequire } from \"module\";\n".to_string(),
+    InitFragmentStage::StageESMImports,
+    0,
+    InitFragmentKey::ModuleExternal("node-commonjs".to_string()),
+    None,
+  )
+  .boxed()
+}
+
+/// Warns at runtime when the `version` of the loaded external `exports` does not satisfy
+/// `expected_version`. Supports exact versions, `^` and `~` ranges and x-ranges such as `18.x`.
+fn get_version_check(exports: &str, expected_version: &str, user_request: &str) -> String {
+  format!(
+    r#"(function(version, range) {{
+  if (typeof version !== "string") return;
+  var parse = function(v) {{ return v.replace(/^[\^~=v]+/, "").split(/[.+-]/).slice(0, 3).map(Number); }};
+  var v = parse(version), r = parse(range), op = range[0];
+  var fixed = op === "^" ? (r[0] ? 1 : r[1] ? 2 : 3) : op === "~" ? 2 : 3;
+  for (var i = 0; i < 3 && !isNaN(r[i]); i++) {{
+    if (v[i] === r[i]) continue;
+    if (i >= fixed && v[i] > r[i]) return;
+    console.warn("Version " + version + " of external module " + {request} + " does not satisfy the expected version " + range);
+    return;
+  }}
+}})({exports} && {exports}.version, {range});"#,
+    request = json_stringify(user_request),
+    range = json_stringify(expected_version),
+  )
+}
+
 fn get_source_for_import(
   module_and_specifiers: &ExternalRequestValue,
   compilation: &Compilation,
@@ -120,6 +619,45 @@ fn get_source_for_import(
   )
 }
 
+/// Imports the external statically with `import * as`, which requires an ES module output.
+fn get_source_for_esm_import(
+  request: &ExternalRequestValue,
+  concatenation_scope: Option<&mut ConcatenationScope>,
+  supports_const: bool,
+  chunk_init_fragments: &mut ChunkInitFragments,
+) -> String {
+  let id = to_identifier(&request.primary);
+  chunk_init_fragments.add(
+    NormalInitFragment::new(
+      format!(
+        "import * as __WEBPACK_EXTERNAL_MODULE_{}__ from {};\n",
+        id.clone(),
+        json_stringify(request.primary())
+      ),
+      InitFragmentStage::StageESMImports,
+      0,
+      InitFragmentKey::ModuleExternal(request.primary().into()),
+      None,
+    )
+    .boxed(),
+  );
+
+  if let Some(concatenation_scope) = concatenation_scope {
+    let external_module_id = format!("__WEBPACK_EXTERNAL_MODULE_{}__", id);
+    let namespace_export_with_name = format!("{}{}", NAMESPACE_OBJECT_EXPORT, &external_module_id);
+    concatenation_scope.register_namespace_export(&namespace_export_with_name);
+    String::new()
+  } else {
+    format!(
+      r#"
+{} = __WEBPACK_EXTERNAL_MODULE_{}__;
+"#,
+      get_namespace_object_export(concatenation_scope, supports_const),
+      id.clone()
+    )
+  }
+}
+
 /**
  * Resolve the detailed external type from the raw external type.
  * e.g. resolve "module" or "import" from "module-import" type
@@ -133,7 +671,7 @@ fn resolve_external_type<'a>(
       if let Some(external_type) = dependency_meta.external_type.as_ref() {
         match external_type {
           ExternalTypeEnum::Import => "import",
-          ExternalTypeEnum::Module => "module",
+          ExternalTypeEnum::Module | ExternalTypeEnum::Wasm => "module",
         }
       } else {
         "module"
@@ -154,6 +692,31 @@ pub struct ExternalModule {
   pub external_type: ExternalType,
   /// Request intended by user (without loaders from config)
   pub user_request: String,
+  /// Pre-computed subresource integrity hash of the external script,
+  /// set on the `<script>` tag created by `__webpack_require__.l`
+  pub integrity: Option<String>,
+  /// Runtimes the external is needed in, other runtimes skip loading it
+  pub runtime_condition: Option<RuntimeSpec>,
+  /// Semver range the loaded external is checked against at runtime, warns on mismatch
+  pub expected_version: Option<String>,
+  /// Code awaited before the external is loaded, set by `before_external_module_init` taps,
+  /// e.g. to pick the external based on a feature flag
+  pub async_init: Option<BoxSource>,
+  /// Reads `window` and `self` externals through a guarded property lookup that yields
+  /// `undefined` when the global is missing, instead of throwing
+  pub csp_safe: bool,
+  /// Checks `__esModule` on commonjs externals when they are loaded, otherwise exposes them as
+  /// the default export only, enabled by `output.strictModuleErrorHandling`
+  pub with_esmodule_interop: bool,
+  /// Source used instead of the generated stub, set by `MockExternalsPlugin` when it is enabled
+  pub with_mock_replacement: Option<BoxSource>,
+  /// Whether `with_mock_replacement` was used when the module was built
+  mocked: bool,
+  /// Whether the request is a `data:` URI exported as an inlined asset, see [`is_asset_data_url`]
+  data_url: bool,
+  /// Class names of a `sass-module` external, read from its manifest when the module is built
+  class_names: Option<BTreeMap<String, String>>,
+  diagnostics: Mutex<Vec<Diagnostic>>,
   factory_meta: Option<FactoryMeta>,
   build_info: Option<BuildInfo>,
   build_meta: Option<BuildMeta>,
@@ -164,6 +727,8 @@ pub struct ExternalModule {
 pub enum ExternalTypeEnum {
   Import,
   Module,
+  /// Imported by a WebAssembly module
+  Wasm,
 }
 
 pub type MetaExternalType = Option<ExternalTypeEnum>;
@@ -180,17 +745,62 @@ impl ExternalModule {
     user_request: String,
     dependency_meta: DependencyMeta,
   ) -> Self {
+    let mut diagnostics: Vec<Diagnostic> = match &request {
+      ExternalRequest::Single(request) => vec![request],
+      ExternalRequest::Map(map) => map.values().collect(),
+      ExternalRequest::Chain(chain) => chain.iter().map(|(_, request)| request).collect(),
+    }
+    .into_iter()
+    // the request of `inline-umd` externals is source code, it may well span lines
+    .filter(|_| external_type != "inline-umd")
+    .filter_map(|request| Self::validate_request(request.primary()).err())
+    .collect();
+    let external_types = match &request {
+      // every item of a fallback chain is generated with its own type
+      ExternalRequest::Chain(chain) => chain
+        .iter()
+        .map(|(external_type, _)| external_type.as_str())
+        .collect(),
+      _ => vec![external_type.as_str()],
+    };
+    diagnostics.extend(external_types.into_iter().filter_map(|external_type| {
+      Self::validate_external_type(external_type, &user_request).err()
+    }));
+    let canonicalize = |external_type: ExternalType| {
+      canonical_external_type(&external_type)
+        .map(|canonical| canonical.to_string())
+        .unwrap_or(external_type)
+    };
+    let external_type = canonicalize(external_type);
+    let request = match request {
+      ExternalRequest::Chain(chain) => ExternalRequest::Chain(
+        chain
+          .into_iter()
+          .map(|(external_type, request)| (canonicalize(external_type), request))
+          .collect(),
+      ),
+      request => request,
+    };
+    let data_url = !matches!(external_type.as_str(), "css-import" | "css-module-import")
+      && matches!(&request, ExternalRequest::Single(request) if is_asset_data_url(request.primary()));
     Self {
       dependencies: Vec::new(),
       blocks: Vec::new(),
-      id: Identifier::from(format!(
-        "external {} {}",
-        resolve_external_type(external_type.as_str(), &dependency_meta),
-        serde_json::to_string(&request).expect("invalid json to_string")
-      )),
+      id: get_external_identifier(&external_type, &dependency_meta, request.clone()),
       request,
       external_type,
       user_request,
+      integrity: None,
+      runtime_condition: None,
+      expected_version: None,
+      csp_safe: false,
+      with_esmodule_interop: false,
+      with_mock_replacement: None,
+      mocked: false,
+      data_url,
+      class_names: None,
+      async_init: None,
+      diagnostics: Mutex::new(diagnostics),
       factory_meta: None,
       build_info: None,
       build_meta: None,
@@ -199,21 +809,509 @@ impl ExternalModule {
     }
   }
 
+  /// Creates an `asset` external for a file of a virtual file system, e.g. a file an IDE plugin
+  /// keeps in memory, served from a synthetic url made of its path and versioned with the hash
+  /// of its content, e.g. `virtual:/src/logo.svg?v=0123456789abcdef`.
+  pub fn from_virtual_entry(path: &str, content: &str) -> Self {
+    Self::from_virtual_content(path, content.as_bytes())
+  }
+
+  /// Reads a file of the input file system, e.g. an in-memory one in tests or IDE plugins, and
+  /// creates an `asset` external for it like [`ExternalModule::from_virtual_entry`].
+  pub fn from_virtual_file(fs: &dyn ReadableFileSystem, path: &Path) -> std::io::Result<Self> {
+    let content = fs.read(path)?;
+    Ok(Self::from_virtual_content(
+      &path.to_string_lossy(),
+      &content,
+    ))
+  }
+
+  fn from_virtual_content(path: &str, content: &[u8]) -> Self {
+    let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+    content.hash(&mut hasher);
+    let hash = hasher.digest(&HashDigest::Hex);
+    Self::new(
+      ExternalRequest::Single(ExternalRequestValue::new(
+        format!("virtual:{}?v={}", path.replace('\\', "/"), hash.encoded()),
+        None,
+      )),
+      "asset".to_string(),
+      path.to_string(),
+      DependencyMeta {
+        external_type: None,
+      },
+    )
+  }
+
+  /// Rewrites the primary request, e.g. from `react@18` to a CDN url, and recomputes the
+  /// identifier from the new request.
+  pub fn with_request_transformer(self, f: impl FnMut(&str) -> String) -> Self {
+    let mut module = Self::new(
+      self.request.map_primary(f),
+      self.external_type,
+      self.user_request,
+      self.dependency_meta,
+    );
+    module.integrity = self.integrity;
+    module.runtime_condition = self.runtime_condition;
+    module.expected_version = self.expected_version;
+    module.async_init = self.async_init;
+    module.csp_safe = self.csp_safe;
+    module.with_esmodule_interop = self.with_esmodule_interop;
+    module.with_mock_replacement = self.with_mock_replacement;
+    module
+  }
+
+  /// Recomputes the identifier from the transformed primary request, e.g. the normalized one,
+  /// so that externals written differently are deduplicated. Unlike
+  /// [`Self::with_request_transformer`], the generated code still loads the request as
+  /// configured.
+  pub fn with_identifier_transformer(mut self, f: impl Fn(&str) -> String) -> Self {
+    self.id = get_external_identifier(
+      &self.external_type,
+      &self.dependency_meta,
+      self.request.clone().map_primary(f),
+    );
+    self
+  }
+
+  /// Normalizes how a package request is written, so that `"react/"` and `"react"` are the
+  /// same external: trailing slashes are stripped, and so is the `.js` extension of subpaths,
+  /// e.g. `"lodash/fp.js"`, when `resolve.extensions` adds it back. The extension of a package
+  /// name such as `"chart.js"` is kept, as are urls and relative or absolute paths.
+  pub fn normalize_request<'a>(request: &'a str, resolve_options: &Resolve) -> Cow<'a, str> {
+    if request.contains(':') || request.starts_with(['.', '/']) {
+      return Cow::Borrowed(request);
+    }
+    let mut normalized = request.trim_end_matches('/');
+    let package_segments = if normalized.starts_with('@') { 2 } else { 1 };
+    if normalized.split('/').count() > package_segments
+      && resolve_options
+        .extensions
+        .as_ref()
+        .is_some_and(|extensions| extensions.iter().any(|extension| extension == ".js"))
+      && let Some(stripped) = normalized.strip_suffix(".js")
+    {
+      normalized = stripped;
+    }
+    if normalized.is_empty() {
+      Cow::Borrowed(request)
+    } else {
+      Cow::Borrowed(normalized)
+    }
+  }
+
+  /// Applies `resolve.alias` to a package request, e.g. `"react"` becomes `"preact/compat"` when
+  /// `react` is aliased to it, so that the external loads the aliased package. As when
+  /// resolving, a key ending with `$` only matches the request exactly, other keys also match
+  /// subpaths such as `"react/jsx-runtime"`. Aliases to relative or absolute paths are not
+  /// applied, the external can't load a file of the project.
+  pub fn alias_request<'a>(request: &'a str, resolve_options: &Resolve) -> Cow<'a, str> {
+    let Some(alias) = &resolve_options.alias else {
+      return Cow::Borrowed(request);
+    };
+    for (key, values) in alias {
+      let subpath = if let Some(exact) = key.strip_suffix('$') {
+        (request == exact).then_some("")
+      } else {
+        request
+          .strip_prefix(key.as_str())
+          .filter(|subpath| subpath.is_empty() || subpath.starts_with('/'))
+      };
+      let Some(subpath) = subpath else {
+        continue;
+      };
+      return match values.iter().find_map(|value| match value {
+        AliasMap::Path(target) => Some(target),
+        AliasMap::Ignore => None,
+      }) {
+        Some(target) if !target.starts_with('.') && !Path::new(target).is_absolute() => {
+          Cow::Owned(format!("{target}{subpath}"))
+        }
+        _ => Cow::Borrowed(request),
+      };
+    }
+    Cow::Borrowed(request)
+  }
+
+  /// Splits a package request such as `"@scope/pkg/sub"` into the package name and the subpath,
+  /// `("@scope/pkg", "/sub")`. Relative, absolute and url requests are not package requests.
+  pub fn split_package_request(request: &str) -> Option<(&str, &str)> {
+    if request.is_empty() || request.starts_with(['.', '/']) || request.contains(':') {
+      return None;
+    }
+    let package_len = request
+      .match_indices('/')
+      .nth(usize::from(request.starts_with('@')))
+      .map_or(request.len(), |(index, _)| index);
+    Some(request.split_at(package_len))
+  }
+
+  /// Expands a package request of the `"unpkg"` and `"esm-sh"` external types to the url of the
+  /// package on that CDN, pinned to the `version` of its `package.json`, e.g. `"react"` becomes
+  /// `"https://esm.sh/react@18.3.1"`. Without a subpath, unpkg loads the `main` file of the
+  /// package. The url is unversioned when the package is not installed.
+  pub fn cdn_request(
+    cdn: &str,
+    request: &str,
+    manifest: Option<&serde_json::Value>,
+  ) -> Option<String> {
+    let (package, subpath) = Self::split_package_request(request)?;
+    let version = manifest
+      .and_then(|manifest| manifest.get("version")?.as_str())
+      .map(|version| format!("@{version}"))
+      .unwrap_or_default();
+    match cdn {
+      "unpkg" => {
+        let main = manifest
+          .filter(|_| subpath.is_empty())
+          .and_then(|manifest| manifest.get("main")?.as_str())
+          .map(|main| format!("/{}", main.trim_start_matches("./")))
+          .unwrap_or_default();
+        Some(format!(
+          "https://unpkg.com/{package}{version}{subpath}{main}"
+        ))
+      }
+      "esm-sh" => Some(format!("https://esm.sh/{package}{version}{subpath}")),
+      _ => None,
+    }
+  }
+
+  /// Emits a debug event describing the external, to find out with `RUST_LOG=rspack_core=debug`
+  /// why a request was externalized: by which plugin and which kind of externals item.
+  pub fn trace_resolved(&self, plugin: &str, matched_by: &str) {
+    tracing::debug!(
+      external_type = %self.external_type,
+      request = %serde_json::to_string(&self.request).unwrap_or_default(),
+      user_request = %self.user_request,
+      plugin,
+      matched_by,
+      "externalized request"
+    );
+  }
+
+  /// Checks that a request can be embedded in the generated code. A request must not be empty
+  /// and must not contain line breaks or null bytes. Being a `&str`, it is always valid UTF-8.
+  pub fn validate_request(request: &str) -> Result<(), Diagnostic> {
+    let reason = if request.is_empty() {
+      "must not be empty"
+    } else if request.contains(['\n', '\r', '\u{2028}', '\u{2029}']) {
+      "must not contain line breaks"
+    } else if request.contains('\0') {
+      "must not contain null bytes"
+    } else {
+      return Ok(());
+    };
+    Err(Diagnostic::error(
+      "Invalid external request".to_string(),
+      format!("External request {} {reason}.", json_stringify(request)),
+    ))
+  }
+
+  /// Reports an external type that is not one of [`KNOWN_EXTERNAL_TYPES`], which would otherwise
+  /// generate an empty module, suggesting the closest known type for typos like `commmonjs`.
+  pub fn validate_external_type(external_type: &str, user_request: &str) -> Result<(), Diagnostic> {
+    if KNOWN_EXTERNAL_TYPES.contains(&external_type) {
+      return Ok(());
+    }
+    let suggestion = KNOWN_EXTERNAL_TYPES
+      .iter()
+      .map(|known| (edit_distance(external_type, known), known))
+      .filter(|(distance, _)| *distance <= (external_type.len() / 3).max(2))
+      .min_by_key(|(distance, _)| *distance)
+      .map(|(_, known)| format!(" Did you mean {}?", json_stringify(known)))
+      .unwrap_or_default();
+    Err(Diagnostic::error(
+      "Unknown external type".to_string(),
+      format!(
+        "Unknown external type {} of external {}.{suggestion} Valid types are: {}.",
+        json_stringify(external_type),
+        json_stringify(user_request),
+        KNOWN_EXTERNAL_TYPES.join(", ")
+      ),
+    ))
+  }
+
   pub fn get_external_type(&self) -> &ExternalType {
     &self.external_type
   }
 
+  /// The request loaded for the external type of the module, e.g. `"lodash"` for
+  /// `{ commonjs: "lodash", amd: "lodash-amd" }` built as `commonjs`, or the first request of a
+  /// fallback chain.
+  pub fn get_primary_request(&self) -> Option<&str> {
+    let (request, _) = self.get_request_and_external_type();
+    request.map(|request| request.primary())
+  }
+
+  /// Human-readable description used in diagnostics, e.g. `external 'var' module 'react'`.
+  pub fn describe(&self) -> String {
+    format!(
+      "external '{}' module '{}'",
+      self.resolve_external_type(),
+      self.user_request
+    )
+  }
+
+  /// Returns the name of the entry an `entry-import` external loads the exports of.
+  pub fn get_entry_import(&self) -> Option<&str> {
+    if self.resolve_external_type() != "entry-import" {
+      return None;
+    }
+    let (request, _) = self.get_request_and_external_type();
+    Some(request?.primary())
+  }
+
+  /// Returns the url and integrity hash of a `script` external, if an integrity hash is provided.
+  pub fn get_script_integrity(&self) -> Option<(&str, &str)> {
+    let integrity = self.integrity.as_deref()?;
+    if self.resolve_external_type() != "script" {
+      return None;
+    }
+    let (request, _) = self.get_request_and_external_type();
+    let url_and_global = extract_url_and_global(request?.primary()).ok()?;
+    Some((url_and_global.url, integrity))
+  }
+
+  /// Returns the condition the external is loaded under when generated for `runtime`.
+  ///
+  /// `None` means it is loaded unconditionally, `false` means it is not needed in any of the
+  /// runtimes, and a spec means it is only needed in some of them.
+  pub fn get_runtime_condition(&self, runtime: Option<&RuntimeSpec>) -> Option<RuntimeCondition> {
+    let runtime_condition = self.runtime_condition.as_ref()?;
+    match runtime {
+      Some(runtime) if runtime.is_subset(runtime_condition) => None,
+      Some(runtime) if runtime.is_disjoint(runtime_condition) => {
+        Some(RuntimeCondition::Boolean(false))
+      }
+      _ => Some(RuntimeCondition::Spec(runtime_condition.clone())),
+    }
+  }
+
+  /// Returns the variable the `amd`, `umd`, `system` and `jsonp` library wrappers bind this
+  /// external to. With `optimization.minifyExternals` it is shortened to `_w<index>`, where
+  /// `index` is the position of the module id among the ids of all external modules.
+  pub fn get_external_variable_name(&self, compilation: &Compilation) -> String {
+    let id = compilation
+      .chunk_graph
+      .chunk_graph_module_by_module_identifier
+      .get(&self.identifier())
+      .and_then(|cgm| cgm.id.as_deref())
+      .unwrap_or_default();
+    if !compilation.options.optimization.minify_externals {
+      return format!("__WEBPACK_EXTERNAL_MODULE_{}__", to_identifier(id));
+    }
+    format!("_w{}", compilation.get_external_module_id_index(id))
+  }
+
+  /// Whether an `import` external is awaited at the top level of its chunk instead of exporting
+  /// the promise of `import()`. Only the async chunks of an ES module output are awaited, so
+  /// the initial chunks are not blocked by loading the external.
+  pub fn is_top_level_await_import(&self, compilation: &Compilation) -> bool {
+    if !compilation.options.output.module || !compilation.options.experiments.top_level_await {
+      return false;
+    }
+    compilation
+      .chunk_graph
+      .chunk_graph_module_by_module_identifier
+      .get(&self.identifier())
+      .is_some_and(|cgm| {
+        !cgm.chunks.is_empty()
+          && cgm.chunks.iter().all(|chunk| {
+            !compilation
+              .chunk_by_ukey
+              .expect_get(chunk)
+              .can_be_initial(&compilation.chunk_group_by_ukey)
+          })
+      })
+  }
+
+  /// Returns the request that replaces this external's request in the chunks it is generated
+  /// in for `runtime`, from `per_chunk_external_overrides`. When the chunks have different
+  /// overrides, the one of the first chunk name in alphabetical order wins.
+  pub fn get_chunk_override<'a>(
+    &self,
+    compilation: &'a Compilation,
+    runtime: Option<&RuntimeSpec>,
+  ) -> Option<&'a str> {
+    let overrides = &compilation.options.per_chunk_external_overrides;
+    if overrides.is_empty() {
+      return None;
+    }
+    let cgm = compilation
+      .chunk_graph
+      .chunk_graph_module_by_module_identifier
+      .get(&self.identifier())?;
+    let mut chunk_names = cgm
+      .chunks
+      .iter()
+      .map(|chunk| compilation.chunk_by_ukey.expect_get(chunk))
+      .filter(|chunk| runtime.map_or(true, |runtime| !chunk.runtime.is_disjoint(runtime)))
+      .filter_map(|chunk| chunk.name.as_deref())
+      .collect::<Vec<_>>();
+    chunk_names.sort_unstable();
+    chunk_names
+      .into_iter()
+      .find_map(|name| overrides.get(name)?.get(&self.user_request))
+      .map(|request| request.as_str())
+  }
+
+  /// Returns the remote url the external is loaded from, used as its source map entry.
+  pub fn get_source_url(&self) -> Option<&str> {
+    let (request, _) = self.get_request_and_external_type();
+    let request = request?.primary();
+    let url = if matches!(self.resolve_external_type(), "script" | "import-script") {
+      extract_url_and_global(request).ok()?.url
+    } else {
+      request
+    };
+    (url.starts_with("http://") || url.starts_with("https://")).then_some(url)
+  }
+
+  fn is_wasm_import(&self) -> bool {
+    matches!(
+      self.dependency_meta.external_type,
+      Some(ExternalTypeEnum::Wasm)
+    )
+  }
+
+  /// Names the WebAssembly modules import from the external, e.g. `getNumber` for
+  /// `(import "./module" "getNumber" (func))`.
+  fn get_wasm_imported_names(
+    &self,
+    compilation: &Compilation,
+    runtime: Option<&RuntimeSpec>,
+  ) -> Vec<String> {
+    let module_graph = compilation.get_module_graph();
+    let mut names = module_graph
+      .get_incoming_connections(&self.id)
+      .into_iter()
+      .filter_map(|connection| module_graph.dependency_by_id(&connection.dependency_id))
+      .filter(|dependency| *dependency.dependency_type() == DependencyType::WasmImport)
+      .flat_map(|dependency| dependency.get_referenced_exports(&module_graph, runtime))
+      .filter_map(|referenced_export| match referenced_export {
+        ExtendedReferencedExport::Array(names) => names.first().map(|name| name.to_string()),
+        ExtendedReferencedExport::Export(export) => {
+          export.name.first().map(|name| name.to_string())
+        }
+      })
+      .collect::<Vec<_>>();
+    names.sort_unstable();
+    names.dedup();
+    names
+  }
+
+  /// Fingerprint of the resolved external type and request, listed in the hot update manifest
+  /// so the client can invalidate its cached reference when an external changes.
+  pub fn hot_update_hash(&self) -> u64 {
+    let mut hasher = FxHasher::default();
+    self.resolve_external_type().hash(&mut hasher);
+    if let ExternalRequest::Chain(chain) = &self.request {
+      for (external_type, request) in chain {
+        external_type.hash(&mut hasher);
+        request.iter().for_each(|request| request.hash(&mut hasher));
+      }
+    } else if let (Some(request), _) = self.get_request_and_external_type() {
+      request.iter().for_each(|request| request.hash(&mut hasher));
+    }
+    hasher.finish()
+  }
+
   fn get_request_and_external_type(&self) -> (Option<&ExternalRequestValue>, &ExternalType) {
     match &self.request {
       ExternalRequest::Single(request) => (Some(request), &self.external_type),
       ExternalRequest::Map(map) => (map.get(&self.external_type), &self.external_type),
+      ExternalRequest::Chain(chain) => (
+        chain.first().map(|(_, request)| request),
+        &self.external_type,
+      ),
+    }
+  }
+
+  /// Warns when the request is a subpath such as `pkg/sub` that the `exports` field of the
+  /// package's `package.json` does not expose. Packages without an `exports` field expose every
+  /// subpath, packages that are not installed are not checked.
+  fn validate_subpath_exports(
+    &self,
+    fs: &dyn ReadableFileSystem,
+    context: &Path,
+  ) -> Option<Diagnostic> {
+    if !matches!(
+      self.resolve_external_type(),
+      "commonjs"
+        | "commonjs2"
+        | "commonjs-module"
+        | "commonjs-static"
+        | "node-commonjs"
+        | "import-sync"
+        | "import"
+        | "import-lazy"
+        | "module"
+    ) {
+      return None;
+    }
+    let (request, _) = self.get_request_and_external_type();
+    let (package, subpath) = Self::split_package_request(request?.primary())?;
+    if subpath.is_empty() {
+      return None;
     }
+    let package_json = context
+      .ancestors()
+      .map(|dir| dir.join("node_modules").join(package).join("package.json"))
+      .find(|path| fs.metadata(path).is_ok())?;
+    let manifest: serde_json::Value = serde_json::from_slice(&fs.read(&package_json).ok()?).ok()?;
+    let exports = manifest.get("exports")?;
+    let subpath = format!(".{subpath}");
+    (!is_subpath_exported(exports, &subpath)).then(|| {
+      Diagnostic::warn(
+        "Unexported subpath".to_string(),
+        format!(
+          "Subpath \"{subpath}\" of {} is not exported by the \"exports\" field of {}.",
+          self.describe(),
+          package_json.display()
+        ),
+      )
+    })
+  }
+
+  /// Reads the class names of a `sass-module` external from a CSS module manifest, e.g. the JSON
+  /// `postcss-modules` writes for a Sass stylesheet, mapping each local class name to the class
+  /// name in the compiled CSS.
+  fn read_class_name_manifest(
+    &self,
+    fs: &dyn ReadableFileSystem,
+    manifest: &Path,
+  ) -> Result<BTreeMap<String, String>, Diagnostic> {
+    let error = |reason: String| {
+      Diagnostic::error(
+        "Invalid CSS module manifest".to_string(),
+        format!(
+          "Failed to read the class names of {} from {}: {reason}",
+          self.describe(),
+          manifest.display()
+        ),
+      )
+    };
+    let content = fs.read(manifest).map_err(|e| error(e.to_string()))?;
+    serde_json::from_slice(&content).map_err(|e| error(e.to_string()))
   }
 
   fn resolve_external_type(&self) -> &str {
     resolve_external_type(self.external_type.as_str(), &self.dependency_meta)
   }
 
+  /// Whether `module.exports` is a promise of the external, like for `script` externals.
+  fn is_exports_promise(&self, compilation: &Compilation) -> bool {
+    match self.resolve_external_type() {
+      "script" | "import-script" | "promise" | "micro-frontend" | "wasm" => true,
+      "import" => {
+        (compilation.options.output.async_chunks || !compilation.options.output.module)
+          && !self.is_top_level_await_import(compilation)
+      }
+      _ => false,
+    }
+  }
+
   fn get_source(
     &self,
     compilation: &Compilation,
@@ -227,11 +1325,32 @@ impl ExternalModule {
     let resolved_external_type = self.resolve_external_type();
 
     let source = match resolved_external_type {
+      _ if let ExternalRequest::Chain(chain) = &self.request => format!(
+        "{} = {};",
+        get_namespace_object_export(concatenation_scope, supports_const),
+        get_source_for_fallback_chain(chain, &compilation.options.output.global_object)?
+      ),
+      "this"
+        if let Some(request) = request
+          && (compilation.options.output.strict_module_error_handling
+            || compilation.options.output.iife) =>
+      {
+        format!(
+          "{} = {};",
+          get_namespace_object_export(concatenation_scope, supports_const),
+          get_source_for_strict_this_external(request)
+        )
+      }
       "this" if let Some(request) = request => format!(
         "{} = (function() {{ return {}; }}());",
         get_namespace_object_export(concatenation_scope, supports_const),
         get_source_for_global_variable_external(request, external_type)
       ),
+      "window" | "self" if self.csp_safe && let Some(request) = request => format!(
+        "{} = {};",
+        get_namespace_object_export(concatenation_scope, supports_const),
+        get_source_for_csp_safe_global_variable_external(request, external_type)
+      ),
       "window" | "self" if let Some(request) = request => format!(
         "{} = {};",
         get_namespace_object_export(concatenation_scope, supports_const),
@@ -242,6 +1361,15 @@ impl ExternalModule {
         get_namespace_object_export(concatenation_scope, supports_const),
         get_source_for_global_variable_external(request, &compilation.options.output.global_object)
       ),
+      "commonjs" | "commonjs2" | "commonjs-module" | "commonjs-static"
+        if self.with_esmodule_interop && let Some(request) = request =>
+      {
+        format!(
+          "{} = {};",
+          get_namespace_object_export(concatenation_scope, supports_const),
+          get_source_for_commonjs_with_esmodule_interop(request)
+        )
+      }
       "commonjs" | "commonjs2" | "commonjs-module" | "commonjs-static"
         if let Some(request) = request =>
       {
@@ -251,19 +1379,10 @@ impl ExternalModule {
           get_source_for_commonjs(request)
         )
       }
-      "node-commonjs" if let Some(request) = request => {
+      // `import-sync` externals are loaded with the same `createRequire` as `node-commonjs` ones
+      "node-commonjs" | "import-sync" if let Some(request) = request => {
         if compilation.options.output.module {
-          chunk_init_fragments.push(
-            NormalInitFragment::new(
-              "import { createRequire as __WEBPACK_EXTERNAL_createRequire } from \"module\";\n"
-                .to_string(),
-              InitFragmentStage::StageESMImports,
-              0,
-              InitFragmentKey::ModuleExternal("node-commonjs".to_string()),
-              None,
-            )
-            .boxed(),
-          );
+          chunk_init_fragments.add(get_create_require_init_fragment());
           format!(
             "{} = __WEBPACK_EXTERNAL_createRequire({}.url)({});",
             get_namespace_object_export(concatenation_scope, supports_const),
@@ -278,23 +1397,146 @@ impl ExternalModule {
           )
         }
       }
-      "amd" | "amd-require" | "umd" | "umd2" | "system" | "jsonp" => {
-        let id = compilation
-          .get_module_graph()
-          .module_graph_module_by_identifier(&self.identifier())
-          .map(|m| m.id(&compilation.chunk_graph))
-          .unwrap_or_default();
+      "dynamic-require" => {
+        if compilation.options.output.module {
+          chunk_init_fragments.add(get_create_require_init_fragment());
+          format!(
+            "{} = __WEBPACK_EXTERNAL_createRequire({}.url);",
+            get_namespace_object_export(concatenation_scope, supports_const),
+            compilation.options.output.import_meta_name
+          )
+        } else {
+          format!(
+            "{} = require;",
+            get_namespace_object_export(concatenation_scope, supports_const)
+          )
+        }
+      }
+      "amd" | "amd-require" | "umd" | "umd2" | "system" | "jsonp" => format!(
+        "{} = {};",
+        get_namespace_object_export(concatenation_scope, supports_const),
+        self.get_external_variable_name(compilation)
+      ),
+      "import"
+        if let Some(request) = request
+          && !compilation.options.output.async_chunks
+          && compilation.options.output.module =>
+      {
+        get_source_for_esm_import(
+          request,
+          concatenation_scope,
+          supports_const,
+          &mut chunk_init_fragments,
+        )
+      }
+      _ if !compilation.options.output.async_chunks && self.is_exports_promise(compilation) => {
+        return Err(error!(
+          "{} is loaded asynchronously, which is not supported when output.asyncChunks is false. Use the \"module\" external type with output.module instead.",
+          self.describe()
+        ));
+      }
+      "import" if let Some(request) = request && self.is_top_level_await_import(compilation) => {
+        let id = format!("__WEBPACK_EXTERNAL_IMPORT_{}__", to_identifier(request.primary()));
+        chunk_init_fragments.add(
+          NormalInitFragment::new(
+            format!(
+              "const {id} = await {};\n",
+              get_source_for_import(request, compilation)
+            ),
+            InitFragmentStage::StageESMImports,
+            0,
+            InitFragmentKey::ModuleExternal(format!("import {}", request.primary())),
+            None,
+          )
+          .boxed(),
+        );
+        format!(
+          "{} = {id};",
+          get_namespace_object_export(concatenation_scope, supports_const),
+        )
+      }
+      "import" if let Some(request) = request => format!(
+        "{} = {};",
+        get_namespace_object_export(concatenation_scope, supports_const),
+        get_source_for_import(request, compilation)
+      ),
+      "import-script" if let Some(request) = request => {
+        let url_and_global = extract_url_and_global(request.primary()).map_err(|_| {
+          error!(
+            "Invalid request \"{}\" of {}, expected \"global@url\"",
+            request.primary(),
+            self.describe()
+          )
+        })?;
+        format!(
+          "{} = {}({}).then(function() {{ return {}; }});",
+          get_namespace_object_export(concatenation_scope, supports_const),
+          compilation.options.output.import_function_name,
+          json_stringify(url_and_global.url),
+          url_and_global.global
+        )
+      }
+      "entry-import" if let Some(request) = request => {
+        let entry = compilation.entries.get(request.primary()).ok_or_else(|| {
+          error!(
+            "{} references entry \"{}\", which does not exist",
+            self.describe(),
+            request.primary()
+          )
+        })?;
+        let dependency = entry.dependencies.first().ok_or_else(|| {
+          error!(
+            "{} references entry \"{}\", which has no modules",
+            self.describe(),
+            request.primary()
+          )
+        })?;
+        format!(
+          "{} = {};",
+          get_namespace_object_export(concatenation_scope, supports_const),
+          module_raw(
+            compilation,
+            &mut runtime_requirements,
+            dependency,
+            request.primary(),
+            false
+          )
+        )
+      }
+      "import-css-worklet" if let Some(request) = request => {
+        chunk_init_fragments.add(
+          NormalInitFragment::new(
+            format!(
+              "if (typeof CSS !== \"undefined\" && CSS.paintWorklet) CSS.paintWorklet.addModule({});\n",
+              json_stringify(request.primary())
+            ),
+            InitFragmentStage::StageESMImports,
+            0,
+            InitFragmentKey::ModuleExternal(format!("import-css-worklet {}", request.primary())),
+            None,
+          )
+          .boxed(),
+        );
         format!(
-          "{} = __WEBPACK_EXTERNAL_MODULE_{}__;",
-          get_namespace_object_export(concatenation_scope, supports_const),
-          to_identifier(id)
+          "{} = {{}};",
+          get_namespace_object_export(concatenation_scope, supports_const)
         )
       }
-      "import" if let Some(request) = request => format!(
-        "{} = {};",
+      "import-lazy" if let Some(request) = request => format!(
+        "{} = function() {{ return {}; }};",
         get_namespace_object_export(concatenation_scope, supports_const),
         get_source_for_import(request, compilation)
       ),
+      "var"
+        if let Some(request) = request
+          && let Some(code) = get_defined_process_env(compilation, request) =>
+      {
+        format!(
+          "{} = ({code}){};",
+          get_namespace_object_export(concatenation_scope, supports_const),
+          property_access(request.iter(), 1)
+        )
+      }
       "var" | "promise" | "const" | "let" | "assign" if let Some(request) = request => format!(
         "{} = {};",
         get_namespace_object_export(concatenation_scope, supports_const),
@@ -302,37 +1544,12 @@ impl ExternalModule {
       ),
       "module" if let Some(request) = request => {
         if compilation.options.output.module {
-          let id = to_identifier(&request.primary);
-          chunk_init_fragments.push(
-            NormalInitFragment::new(
-              format!(
-                "import * as __WEBPACK_EXTERNAL_MODULE_{}__ from {};\n",
-                id.clone(),
-                json_stringify(request.primary())
-              ),
-              InitFragmentStage::StageESMImports,
-              0,
-              InitFragmentKey::ModuleExternal(request.primary().into()),
-              None,
-            )
-            .boxed(),
-          );
-
-          if let Some(concatenation_scope) = concatenation_scope {
-            let external_module_id = format!("__WEBPACK_EXTERNAL_MODULE_{}__", id);
-            let namespace_export_with_name =
-              format!("{}{}", NAMESPACE_OBJECT_EXPORT, &external_module_id);
-            concatenation_scope.register_namespace_export(&namespace_export_with_name);
-            String::new()
-          } else {
-            format!(
-              r#"
-{} = __WEBPACK_EXTERNAL_MODULE_{}__;
-"#,
-              get_namespace_object_export(concatenation_scope, supports_const),
-              id.clone()
-            )
-          }
+          get_source_for_esm_import(
+            request,
+            concatenation_scope,
+            supports_const,
+            &mut chunk_init_fragments,
+          )
         } else {
           format!(
             "{} = {};",
@@ -341,8 +1558,62 @@ impl ExternalModule {
           )
         }
       }
+      "wasm" if let Some(request) = request => format!(
+        "{} = WebAssembly.instantiateStreaming(fetch({})).then(function(result) {{ return result.instance.exports; }});",
+        get_namespace_object_export(concatenation_scope, supports_const),
+        json_stringify(request.primary())
+      ),
+      "micro-frontend" if let Some(request) = request => {
+        runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+        runtime_requirements.insert(RuntimeGlobals::FEDERATION);
+        format!(
+          "{} = {};",
+          get_namespace_object_export(concatenation_scope, supports_const),
+          get_source_for_micro_frontend(request)
+        )
+      }
+      "remote-entry-v2" if let Some(request) = request => {
+        runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+        runtime_requirements.insert(RuntimeGlobals::FEDERATION);
+        format!(
+          "{} = {};",
+          get_namespace_object_export(concatenation_scope, supports_const),
+          get_source_for_remote_entry_v2(request)
+        )
+      }
+      "federation-delegate" if let Some(request) = request => {
+        runtime_requirements.insert(RuntimeGlobals::SHARE_SCOPE_MAP);
+        format!(
+          "{} = {};",
+          get_namespace_object_export(concatenation_scope, supports_const),
+          get_source_for_federation_delegate(request)
+        )
+      }
+      "sass-module" => format!(
+        "{} = {};",
+        get_namespace_object_export(concatenation_scope, supports_const),
+        serde_json::to_string(self.class_names.as_ref().unwrap_or(&BTreeMap::new()))
+          .map_err(|e| error!(e.to_string()))?
+      ),
+      "inline-umd" if let Some(request) = request => format!(
+        "{} = {};",
+        get_namespace_object_export(concatenation_scope, supports_const),
+        get_source_for_inline_umd(request.primary())
+      ),
+      // ES module output turns it into a "module" external, other outputs cannot load the bare
+      // specifier so they only get an empty namespace
+      "bare-specifier" => format!(
+        "{} = {{ [Symbol.toStringTag]: \"Module\" }};",
+        get_namespace_object_export(concatenation_scope, supports_const)
+      ),
       "script" if let Some(request) = request => {
-        let url_and_global = extract_url_and_global(request.primary())?;
+        let url_and_global = extract_url_and_global(request.primary()).map_err(|_| {
+          error!(
+            "Invalid request \"{}\" of {}, expected \"global@url\"",
+            request.primary(),
+            self.describe()
+          )
+        })?;
         runtime_requirements.insert(RuntimeGlobals::LOAD_SCRIPT);
         format!(
           r#"
@@ -417,6 +1688,18 @@ impl Module for ExternalModule {
         // return `${this.externalType} externals can't be concatenated`;
         Some(format!("{} externals can't be concatenated", self.external_type).into())
       }
+      _ if self.async_init.is_some() => {
+        Some("externals with an async init can't be concatenated".into())
+      }
+      _ if self.mocked => Some("mocked externals can't be concatenated".into()),
+      // the runtime condition is emitted with the module, which concatenation drops
+      _ if self.runtime_condition.is_some() => {
+        Some("externals with a runtime condition can't be concatenated".into())
+      }
+      // the version check is emitted with the module, which concatenation drops
+      _ if self.expected_version.is_some() => {
+        Some("externals with an expected version can't be concatenated".into())
+      }
       _ => None,
     }
   }
@@ -426,19 +1709,25 @@ impl Module for ExternalModule {
   }
 
   fn get_diagnostics(&self) -> Vec<Diagnostic> {
-    vec![]
+    self.clone_diagnostics()
   }
 
   fn source_types(&self) -> &[SourceType] {
-    if self.external_type == "css-import" {
-      EXTERNAL_MODULE_CSS_SOURCE_TYPES
-    } else {
-      EXTERNAL_MODULE_JS_SOURCE_TYPES
+    match self.external_type.as_str() {
+      "css-import" => EXTERNAL_MODULE_CSS_SOURCE_TYPES,
+      "css-module-import" => EXTERNAL_MODULE_CSS_MODULE_SOURCE_TYPES,
+      "css-property" => EXTERNAL_MODULE_CSS_PROPERTY_SOURCE_TYPES,
+      _ if self.data_url => EXTERNAL_MODULE_DATA_URL_SOURCE_TYPES,
+      _ if self.is_wasm_import() => EXTERNAL_MODULE_WASM_SOURCE_TYPES,
+      _ => EXTERNAL_MODULE_JS_SOURCE_TYPES,
     }
   }
 
   fn chunk_condition(&self, chunk_key: &ChunkUkey, compilation: &Compilation) -> Option<bool> {
-    if self.external_type == "css-import" {
+    if matches!(
+      self.external_type.as_str(),
+      "css-import" | "css-module-import" | "css-property"
+    ) {
       return Some(true);
     }
     Some(
@@ -449,6 +1738,17 @@ impl Module for ExternalModule {
     )
   }
 
+  fn get_side_effects_connection_state(
+    &self,
+    _module_graph: &ModuleGraph,
+    _module_chain: &mut IdentifierSet,
+  ) -> ConnectionState {
+    if let Some(side_effect_free) = self.factory_meta().and_then(|m| m.side_effect_free) {
+      return ConnectionState::Bool(!side_effect_free);
+    }
+    ConnectionState::Bool(self.build_meta().and_then(|m| m.side_effect_free) != Some(true))
+  }
+
   fn original_source(&self) -> Option<&dyn Source> {
     None
   }
@@ -468,9 +1768,10 @@ impl Module for ExternalModule {
 
   async fn build(
     &mut self,
-    _build_context: BuildContext<'_>,
+    build_context: BuildContext<'_>,
     _: Option<&Compilation>,
   ) -> Result<BuildResult> {
+    self.mocked = self.with_mock_replacement.is_some();
     let resolved_external_type = self.resolve_external_type();
     let build_info = BuildInfo {
       top_level_declarations: Some(FxHashSet::default()),
@@ -490,13 +1791,87 @@ impl Module for ExternalModule {
       "this" => build_result.build_info.strict = false,
       "system" => build_result.build_meta.exports_type = BuildMetaExportsType::Namespace,
       "module" => build_result.build_meta.exports_type = BuildMetaExportsType::Namespace,
-      "script" | "promise" => build_result.build_meta.has_top_level_await = true,
+      "script" | "import-script" | "promise" | "micro-frontend" => {
+        build_result.build_meta.has_top_level_await = true
+      }
+      "wasm" => {
+        build_result.build_meta.has_top_level_await = true;
+        build_result.build_meta.exports_type = BuildMetaExportsType::Namespace;
+      }
+      "import"
+        if !build_context.compiler_options.output.async_chunks
+          && build_context.compiler_options.output.module =>
+      {
+        build_result.build_meta.exports_type = BuildMetaExportsType::Namespace;
+      }
       "import" => {
         build_result.build_meta.has_top_level_await = true;
         build_result.build_meta.exports_type = BuildMetaExportsType::Namespace;
       }
+      "commonjs" | "commonjs2" | "commonjs-module" | "commonjs-static"
+        if self.with_esmodule_interop =>
+      {
+        build_result.build_meta.exports_type = BuildMetaExportsType::Namespace;
+      }
       _ => build_result.build_meta.exports_type = BuildMetaExportsType::Dynamic,
     }
+    if self.async_init.is_some() {
+      build_result.build_meta.has_top_level_await = true;
+    }
+    // the mock is a plain commonjs module
+    if self.mocked {
+      build_result.build_meta.exports_type = BuildMetaExportsType::Dynamic;
+      build_result.build_meta.has_top_level_await = false;
+    }
+    if resolved_external_type == "dynamic-require" {
+      self.add_diagnostic(
+        Diagnostic::warn(
+          "Dynamic require".to_string(),
+          format!(
+            "{} exports the require function, modules required through it at runtime bypass static analysis and are not bundled.",
+            self.describe()
+          ),
+        )
+        .with_module_identifier(Some(self.id)),
+      );
+    }
+    let mut class_names = None;
+    if resolved_external_type == "sass-module"
+      && let (Some(request), _) = self.get_request_and_external_type()
+    {
+      let context: &Path = build_context.compiler_options.context.as_ref();
+      let manifest = context.join(request.primary());
+      match self.read_class_name_manifest(build_context.fs.as_ref(), &manifest) {
+        Ok(names) => class_names = Some(names),
+        Err(diagnostic) => self.add_diagnostic(diagnostic.with_module_identifier(Some(self.id))),
+      }
+      build_result.build_info.file_dependencies.insert(manifest);
+    }
+    if build_context.compiler_options.validate_subpath_exports
+      && let Some(diagnostic) = self.validate_subpath_exports(
+        build_context.fs.as_ref(),
+        build_context.compiler_options.context.as_ref(),
+      )
+    {
+      self.add_diagnostic(diagnostic);
+    }
+    // Reading a global variable has no side effects, so the external can be dropped when
+    // nothing uses it, e.g. when it is only re-exported by a module whose exports are unused.
+    if !build_context
+      .compiler_options
+      .optimization
+      .side_effects
+      .is_false()
+      && matches!(
+        resolved_external_type,
+        "var" | "window" | "self" | "global" | "this"
+      )
+      && !matches!(self.request, ExternalRequest::Chain(_))
+      && !self.mocked
+    {
+      build_result.build_meta.side_effect_free = Some(true);
+    }
+    self.class_names = class_names;
     build_result
       .dependencies
       .push(Box::new(StaticExportsDependency::new(
@@ -510,13 +1885,16 @@ impl Module for ExternalModule {
   fn code_generation(
     &self,
     compilation: &Compilation,
-    _runtime: Option<&RuntimeSpec>,
+    runtime: Option<&RuntimeSpec>,
     mut concatenation_scope: Option<ConcatenationScope>,
   ) -> Result<CodeGenerationResult> {
     let mut cgr = CodeGenerationResult::default();
     let (request, external_type) = self.get_request_and_external_type();
     match self.external_type.as_str() {
-      "asset" if let Some(request) = request => {
+      // The url of `data:` externals is inlined, there is no asset file to emit
+      _ if (self.external_type == "asset" || self.data_url)
+        && let Some(request) = request =>
+      {
         cgr.add(
           SourceType::JavaScript,
           RawSource::from(format!(
@@ -539,14 +1917,105 @@ impl Module for ExternalModule {
           .boxed(),
         );
       }
+      "css-property" if let Some(request) = request => {
+        cgr.add(
+          SourceType::Css,
+          RawSource::from(get_source_for_css_property(request.primary())?).boxed(),
+        );
+      }
+      "css-module-import" if let Some(request) = request => {
+        cgr.add(
+          SourceType::Css,
+          RawSource::from(format!(
+            "@import url({});",
+            serde_json::to_string(request.primary()).map_err(|e| error!(e.to_string()))?
+          ))
+          .boxed(),
+        );
+        // `composes: a from "<request>"` resolves the class names of the external stylesheet
+        // through the module exports. The stylesheet is only loaded at runtime, so there is no
+        // class map to check against: every class name is exported as is, including ones the
+        // stylesheet does not define
+        cgr.add(
+          SourceType::JavaScript,
+          RawSource::from(
+            "module.exports = new Proxy({}, { get: function(_, name) { return typeof name === \"string\" ? name : undefined; } });",
+          )
+          .boxed(),
+        );
+      }
+      _ if self.mocked
+        && let Some(mock_replacement) = &self.with_mock_replacement =>
+      {
+        cgr.add(SourceType::JavaScript, mock_replacement.clone());
+      }
+      _ if concatenation_scope.is_none()
+        && let Some(RuntimeCondition::Boolean(false)) = self.get_runtime_condition(runtime) =>
+      {
+        cgr.add(SourceType::JavaScript, RawSource::from("").boxed());
+      }
       _ => {
-        let (source, chunk_init_fragments, runtime_requirements) = self.get_source(
+        let overridden_request = self
+          .get_chunk_override(compilation, runtime)
+          .map(|primary| ExternalRequestValue::new(primary.to_string(), None));
+        let (mut source, chunk_init_fragments, mut runtime_requirements) = self.get_source(
           compilation,
-          request,
+          overridden_request.as_ref().or(request),
           external_type,
           concatenation_scope.as_mut(),
         )?;
+        if concatenation_scope.is_none()
+          && let Some(expected_version) = &self.expected_version
+        {
+          // the exports of async externals are a promise, they are checked once it resolves
+          let check = if self.is_exports_promise(compilation) {
+            format!(
+              "module.exports = module.exports.then(function(exports) {{\n{}\nreturn exports;\n}});",
+              get_version_check("exports", expected_version, &self.user_request)
+            )
+          } else {
+            get_version_check("module.exports", expected_version, &self.user_request)
+          };
+          source = RawSource::from(format!("{}\n{check}", source.source())).boxed();
+        }
+        if let Some(async_init) = &self.async_init {
+          source = RawSource::from(get_source_with_async_init(
+            &async_init.source(),
+            &source.source(),
+          ))
+          .boxed();
+        }
+        if concatenation_scope.is_none()
+          && let Some(runtime_condition) = self.get_runtime_condition(runtime)
+        {
+          let condition = runtime_condition_expression(
+            &compilation.chunk_graph,
+            Some(&runtime_condition),
+            runtime,
+            &mut runtime_requirements,
+          );
+          source = RawSource::from(format!("if ({condition}) {{\n{}\n}}", source.source())).boxed();
+        }
+        // named by the identifier, the `SourceMapDevToolPlugin` maps it to the url of the module
+        if self.get_source_map_kind().enabled() && self.get_source_url().is_some() {
+          source = OriginalSource::new(source.source(), self.id.as_str()).boxed();
+        }
         cgr.add(SourceType::JavaScript, source);
+        if self.is_wasm_import()
+          && !matches!(
+            compilation.options.output.wasm_loading,
+            WasmLoading::Disable
+          )
+        {
+          cgr.add(
+            SourceType::Wasm,
+            RawSource::from(get_source_for_wasm_imports(
+              &self.user_request,
+              &self.get_wasm_imported_names(compilation, runtime),
+            ))
+            .boxed(),
+          );
+        }
         cgr.chunk_init_fragments = chunk_init_fragments;
         cgr.runtime_requirements.insert(runtime_requirements);
         cgr.set_hash(
@@ -574,11 +2043,920 @@ impl Module for ExternalModule {
     runtime: Option<&RuntimeSpec>,
   ) -> Result<()> {
     self.id.dyn_hash(hasher);
+    // the runtimes the external is skipped in share a different code generation result
+    match self.get_runtime_condition(runtime) {
+      None => None::<&RuntimeSpec>.dyn_hash(hasher),
+      Some(RuntimeCondition::Boolean(value)) => value.dyn_hash(hasher),
+      Some(RuntimeCondition::Spec(runtime_condition)) => Some(&runtime_condition).dyn_hash(hasher),
+    }
+    self.expected_version.dyn_hash(hasher);
+    if let Some(async_init) = &self.async_init {
+      async_init.source().dyn_hash(hasher);
+    }
+    if let Some(entry) = self.get_entry_import()
+      && let Some(dependency) = compilation
+        .entries
+        .get(entry)
+        .and_then(|entry| entry.dependencies.first())
+      && let Some(module) = compilation
+        .get_module_graph()
+        .module_identifier_by_dependency_id(dependency)
+    {
+      compilation
+        .chunk_graph
+        .get_module_id(*module)
+        .dyn_hash(hasher);
+    }
+    if self.resolve_external_type() == "import" {
+      self.is_top_level_await_import(compilation).dyn_hash(hasher);
+    }
+    self
+      .get_chunk_override(compilation, runtime)
+      .dyn_hash(hasher);
     let is_optional = compilation.get_module_graph().is_optional(&self.id);
     is_optional.dyn_hash(hasher);
+    self.csp_safe.dyn_hash(hasher);
+    self.with_esmodule_interop.dyn_hash(hasher);
+    // the class names are read from the manifest of `sass-module` externals in `build`
+    self.class_names.dyn_hash(hasher);
+    if self.mocked
+      && let Some(mock_replacement) = &self.with_mock_replacement
+    {
+      mock_replacement.source().dyn_hash(hasher);
+    }
+    if self.resolve_external_type() == "var"
+      && let (Some(request), _) = self.get_request_and_external_type()
+    {
+      get_defined_process_env(compilation, request).dyn_hash(hasher);
+    }
+    if self.is_wasm_import() {
+      self
+        .get_wasm_imported_names(compilation, runtime)
+        .dyn_hash(hasher);
+    }
     module_update_hash(self, hasher, compilation, runtime);
     Ok(())
   }
 }
 
-impl_empty_diagnosable_trait!(ExternalModule);
+impl Diagnosable for ExternalModule {
+  fn add_diagnostic(&self, diagnostic: Diagnostic) {
+    self
+      .diagnostics
+      .lock()
+      .expect("should be able to lock diagnostics")
+      .push(diagnostic);
+  }
+
+  fn add_diagnostics(&self, mut diagnostics: Vec<Diagnostic>) {
+    self
+      .diagnostics
+      .lock()
+      .expect("should be able to lock diagnostics")
+      .append(&mut diagnostics);
+  }
+
+  fn clone_diagnostics(&self) -> Vec<Diagnostic> {
+    self
+      .diagnostics
+      .lock()
+      .expect("should be able to lock diagnostics")
+      .iter()
+      .cloned()
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod external_module_golden;
+#[cfg(test)]
+mod external_module_proptest;
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn create_external_module(request: &str, external_type: &str) -> ExternalModule {
+    ExternalModule::new(
+      ExternalRequest::Single(ExternalRequestValue::new(request.to_string(), None)),
+      external_type.to_string(),
+      request.to_string(),
+      DependencyMeta {
+        external_type: None,
+      },
+    )
+  }
+
+  #[test]
+  fn test_canonicalize_request() {
+    assert_eq!(canonicalize_request("./lodash"), "lodash");
+    assert_eq!(canonicalize_request("././lodash"), "lodash");
+    assert_eq!(canonicalize_request("./@scope/pkg"), "@scope/pkg");
+    assert_eq!(canonicalize_request("./foo/bar"), "foo/bar");
+    assert_eq!(canonicalize_request("lodash"), "lodash");
+    assert_eq!(canonicalize_request("../foo"), "../foo");
+    assert_eq!(canonicalize_request("/abs/path"), "/abs/path");
+    assert_eq!(canonicalize_request("./"), "./");
+    assert_eq!(canonicalize_request("./../foo"), "./../foo");
+  }
+
+  #[test]
+  fn test_identifier_ignores_redundant_prefix() {
+    let create = |request| create_external_module(request, "commonjs");
+    assert_eq!(
+      create("./lodash").identifier(),
+      create("lodash").identifier()
+    );
+    assert_ne!(
+      create("../lodash").identifier(),
+      create("lodash").identifier()
+    );
+    assert_eq!(create("./lodash").get_primary_request(), Some("./lodash"));
+  }
+
+  #[test]
+  fn test_identifier_transformer_keeps_request() {
+    let resolve = Resolve {
+      extensions: Some(vec![".js".to_string()]),
+      ..Default::default()
+    };
+    let create = |request| {
+      create_external_module(request, "commonjs").with_identifier_transformer(|request| {
+        ExternalModule::normalize_request(request, &resolve).into_owned()
+      })
+    };
+    assert_eq!(
+      create("lodash/fp.js").identifier(),
+      create("lodash/fp").identifier()
+    );
+    assert_eq!(
+      create("lodash/fp.js").get_primary_request(),
+      Some("lodash/fp.js")
+    );
+  }
+
+  #[test]
+  fn test_script_integrity() {
+    let create = |external_type, integrity: Option<&str>| {
+      let mut module =
+        create_external_module("React@https://cdn.example.com/react.js", external_type);
+      module.integrity = integrity.map(|i| i.to_string());
+      module
+    };
+    assert_eq!(
+      create("script", Some("sha384-abc")).get_script_integrity(),
+      Some(("https://cdn.example.com/react.js", "sha384-abc"))
+    );
+    assert_eq!(create("script", None).get_script_integrity(), None);
+    assert_eq!(
+      create("var", Some("sha384-abc")).get_script_integrity(),
+      None
+    );
+  }
+
+  #[test]
+  fn test_runtime_condition() {
+    let browser = RuntimeSpec::from_iter(["browser".into()]);
+    let node = RuntimeSpec::from_iter(["node".into()]);
+    let both = RuntimeSpec::from_iter(["browser".into(), "node".into()]);
+
+    let mut module = create_external_module("react-dom", "var");
+    assert_eq!(module.get_runtime_condition(Some(&browser)), None);
+
+    module.runtime_condition = Some(browser.clone());
+    assert_eq!(module.get_runtime_condition(Some(&browser)), None);
+    assert_eq!(
+      module.get_runtime_condition(Some(&node)),
+      Some(RuntimeCondition::Boolean(false))
+    );
+    assert_eq!(
+      module.get_runtime_condition(Some(&both)),
+      Some(RuntimeCondition::Spec(browser.clone()))
+    );
+    assert_eq!(
+      module.get_runtime_condition(None),
+      Some(RuntimeCondition::Spec(browser))
+    );
+  }
+
+  #[test]
+  fn test_css_module_import_source_types() {
+    assert_eq!(
+      create_external_module(
+        "https://cdn.example.com/button.module.css",
+        "css-module-import"
+      )
+      .source_types(),
+      &[SourceType::JavaScript, SourceType::CssImport]
+    );
+    assert_eq!(
+      create_external_module("https://cdn.example.com/button.css", "css-import").source_types(),
+      &[SourceType::CssImport]
+    );
+  }
+
+  #[derive(Clone, Default)]
+  struct LogWriter(std::sync::Arc<Mutex<Vec<u8>>>);
+
+  impl std::io::Write for LogWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+      self
+        .0
+        .lock()
+        .expect("should lock logs")
+        .extend_from_slice(buf);
+      Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+      Ok(())
+    }
+  }
+
+  #[test]
+  fn test_trace_resolved() {
+    let writer = LogWriter::default();
+    let subscriber = tracing_subscriber::fmt()
+      .with_max_level(tracing::Level::DEBUG)
+      .with_ansi(false)
+      .with_writer({
+        let writer = writer.clone();
+        move || writer.clone()
+      })
+      .finish();
+    tracing::subscriber::with_default(subscriber, || {
+      create_external_module("react", "commonjs")
+        .trace_resolved("rspack.ExternalsPlugin", "object");
+    });
+    let logs = String::from_utf8(writer.0.lock().expect("should lock logs").clone())
+      .expect("logs should be utf-8");
+    assert!(logs.contains("DEBUG rspack_core::external_module"));
+    assert!(logs.contains("externalized request"));
+    assert!(logs.contains("external_type=commonjs"));
+    assert!(logs.contains(r#"request="react""#));
+    assert!(logs.contains("user_request=react"));
+    assert!(logs.contains(r#"plugin="rspack.ExternalsPlugin""#));
+    assert!(logs.contains(r#"matched_by="object""#));
+  }
+
+  #[test]
+  fn test_is_asset_data_url() {
+    assert!(is_asset_data_url(
+      "data:image/svg+xml,%3Csvg%20xmlns='http://www.w3.org/2000/svg'/%3E"
+    ));
+    assert!(is_asset_data_url(
+      "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII="
+    ));
+    assert!(is_asset_data_url("data:,hello"));
+    assert!(!is_asset_data_url("data:text/javascript,export default 42"));
+    assert!(!is_asset_data_url(
+      "data:application/javascript;base64,ZXhwb3J0IGRlZmF1bHQgNDI="
+    ));
+    assert!(!is_asset_data_url("data:image/png"));
+    assert!(!is_asset_data_url("https://cdn.example.com/logo.svg"));
+  }
+
+  #[test]
+  fn test_data_url_source_types() {
+    assert_eq!(
+      create_external_module("data:image/svg+xml,%3Csvg/%3E", "var").source_types(),
+      &[SourceType::Asset, SourceType::JavaScript]
+    );
+    assert_eq!(
+      create_external_module("data:image/png;base64,iVBORw0KGgo=", "commonjs").source_types(),
+      &[SourceType::Asset, SourceType::JavaScript]
+    );
+    assert_eq!(
+      create_external_module("data:text/javascript,export default 42", "import").source_types(),
+      &[SourceType::JavaScript]
+    );
+    assert_eq!(
+      create_external_module("data:text/css,a{}", "css-import").source_types(),
+      &[SourceType::CssImport]
+    );
+  }
+
+  #[test]
+  fn test_describe() {
+    assert_eq!(
+      create_external_module("react", "var").describe(),
+      "external 'var' module 'react'"
+    );
+    assert_eq!(
+      create_external_module("./lodash", "commonjs").describe(),
+      "external 'commonjs' module './lodash'"
+    );
+  }
+
+  #[test]
+  fn test_wasm_imports() {
+    assert_eq!(
+      get_source_for_wasm_imports("./module", &["getNumber".to_string(), "log".to_string()]),
+      "(import \"./module\" \"getNumber\" (func))\n(import \"./module\" \"log\" (func))"
+    );
+    assert_eq!(get_source_for_wasm_imports("./module", &[]), "");
+  }
+
+  #[test]
+  fn test_validate_external_type() {
+    for external_type in KNOWN_EXTERNAL_TYPES {
+      assert!(ExternalModule::validate_external_type(external_type, "lodash").is_ok());
+    }
+
+    let message = |external_type: &str| {
+      ExternalModule::validate_external_type(external_type, "lodash")
+        .expect_err("should be unknown")
+        .message()
+        .to_string()
+    };
+    let typo = message("commmonjs");
+    assert!(typo.contains(r#"Unknown external type "commmonjs" of external "lodash"."#));
+    assert!(typo.contains(r#"Did you mean "commonjs"?"#));
+    assert!(typo.contains("Valid types are: amd, amd-require, "));
+    assert!(message("windoww").contains(r#"Did you mean "window"?"#));
+    assert!(message("Module").contains(r#"Did you mean "module"?"#));
+    assert!(!message("xyz").contains("Did you mean"));
+
+    let module = create_external_module("lodash", "commmonjs");
+    let diagnostics = module.clone_diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0]
+      .message()
+      .contains("Did you mean \"commonjs\"?"));
+    assert!(create_external_module("lodash", "commonjs")
+      .clone_diagnostics()
+      .is_empty());
+
+    let module = ExternalModule::new(
+      ExternalRequest::Chain(vec![
+        (
+          "commonjs".to_string(),
+          ExternalRequestValue::new("lodash".to_string(), None),
+        ),
+        (
+          "windw".to_string(),
+          ExternalRequestValue::new("_".to_string(), None),
+        ),
+      ]),
+      "var".to_string(),
+      "lodash".to_string(),
+      DependencyMeta {
+        external_type: None,
+      },
+    );
+    let diagnostics = module.clone_diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0]
+      .message()
+      .contains("Did you mean \"window\"?"));
+  }
+
+  #[test]
+  fn test_known_external_types() {
+    for external_type in ["const", "let", "cjs", "cjs2"] {
+      assert!(KNOWN_EXTERNAL_TYPES.contains(&external_type));
+    }
+    for external_type in KNOWN_EXTERNAL_TYPES {
+      let module = create_external_module("lodash", external_type);
+      assert!(
+        !module
+          .clone_diagnostics()
+          .iter()
+          .any(|diagnostic| diagnostic.message().contains("Unknown external type")),
+        "{external_type} should be accepted"
+      );
+    }
+    assert_eq!(
+      create_external_module("lodash", "cjs").get_external_type(),
+      "commonjs"
+    );
+    assert_eq!(
+      create_external_module("lodash", "cjs2").get_external_type(),
+      "commonjs2"
+    );
+    assert_eq!(
+      create_external_module("lodash", "commonjs-static").get_external_type(),
+      "commonjs-static"
+    );
+  }
+
+  #[test]
+  fn test_edit_distance() {
+    assert_eq!(edit_distance("commonjs", "commonjs"), 0);
+    assert_eq!(edit_distance("commmonjs", "commonjs"), 1);
+    assert_eq!(edit_distance("umd", "umd2"), 1);
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+    assert_eq!(edit_distance("", "var"), 3);
+  }
+
+  #[test]
+  fn test_micro_frontend() {
+    let request = |primary: &str| ExternalRequestValue::new(primary.to_string(), None);
+    assert_eq!(
+      get_source_for_micro_frontend(&request("mf://app/Button")),
+      "__webpack_require__.federation.loadRemote(\"app/Button\")"
+    );
+    assert_eq!(
+      get_source_for_micro_frontend(&request("app/Button")),
+      "__webpack_require__.federation.loadRemote(\"app/Button\")"
+    );
+  }
+
+  /// Serves files from memory, e.g. the manifest of a `sass-module` external.
+  #[derive(Default)]
+  struct MemoryFileSystem(HashMap<std::path::PathBuf, Vec<u8>>);
+
+  impl MemoryFileSystem {
+    fn with_file(mut self, path: &str, content: &str) -> Self {
+      self.0.insert(path.into(), content.as_bytes().to_vec());
+      self
+    }
+  }
+
+  impl ReadableFileSystem for MemoryFileSystem {
+    fn read(&self, path: &Path) -> std::io::Result<Vec<u8>> {
+      self
+        .0
+        .get(path)
+        .cloned()
+        .ok_or_else(|| std::io::ErrorKind::NotFound.into())
+    }
+
+    fn metadata(&self, _: &Path) -> std::io::Result<std::fs::Metadata> {
+      Err(std::io::ErrorKind::Unsupported.into())
+    }
+
+    fn symlink_metadata(&self, _: &Path) -> std::io::Result<std::fs::Metadata> {
+      Err(std::io::ErrorKind::Unsupported.into())
+    }
+
+    fn canonicalize(&self, _: &Path) -> std::io::Result<std::path::PathBuf> {
+      Err(std::io::ErrorKind::Unsupported.into())
+    }
+  }
+
+  #[test]
+  fn test_read_class_name_manifest() {
+    let module = create_external_module("./button.module.json", "sass-module");
+    let manifest = Path::new("/project/button.module.json");
+
+    let class_names = module
+      .read_class_name_manifest(
+        &MemoryFileSystem::default().with_file(
+          "/project/button.module.json",
+          r#"{ "container": "container__abc123", "title": "title__def456" }"#,
+        ),
+        manifest,
+      )
+      .expect("should read the manifest");
+    assert_eq!(
+      serde_json::to_string(&class_names).expect("should serialize"),
+      r#"{"container":"container__abc123","title":"title__def456"}"#
+    );
+
+    let invalid = module
+      .read_class_name_manifest(
+        &MemoryFileSystem::default()
+          .with_file("/project/button.module.json", r#"{ "container": 1 }"#),
+        manifest,
+      )
+      .expect_err("class names should be strings");
+    assert!(invalid
+      .message()
+      .contains("Failed to read the class names of external 'sass-module' module './button.module.json' from /project/button.module.json"));
+
+    assert!(module
+      .read_class_name_manifest(
+        &MemoryFileSystem::default().with_file("/project/button.module.json", "{}"),
+        Path::new("/project/missing.module.json")
+      )
+      .is_err());
+  }
+
+  #[test]
+  fn test_remote_entry_v2() {
+    let source =
+      get_source_for_remote_entry_v2(&ExternalRequestValue::new("app".to_string(), None));
+    assert_eq!(source, "__webpack_require__.federation.getRemote(\"app\")");
+  }
+
+  #[test]
+  fn test_from_virtual_entry() {
+    let module = ExternalModule::from_virtual_entry("/src/logo.svg", "<svg></svg>");
+    assert_eq!(module.get_external_type(), "asset");
+    assert_eq!(module.user_request, "/src/logo.svg");
+    let url = module.get_primary_request().expect("should have a request");
+    assert!(url.starts_with("virtual:/src/logo.svg?v="), "{url}");
+    assert_eq!(
+      url,
+      ExternalModule::from_virtual_entry("/src/logo.svg", "<svg></svg>")
+        .get_primary_request()
+        .expect("should have a request")
+    );
+    assert_ne!(
+      url,
+      ExternalModule::from_virtual_entry("/src/logo.svg", "<svg />")
+        .get_primary_request()
+        .expect("should have a request")
+    );
+    assert_eq!(
+      ExternalModule::from_virtual_entry("C:\\src\\logo.svg", "")
+        .get_primary_request()
+        .map(|url| url.starts_with("virtual:C:/src/logo.svg?v=")),
+      Some(true)
+    );
+    assert_eq!(
+      module.source_types(),
+      &[SourceType::JavaScript],
+      "a virtual url is not a data url"
+    );
+  }
+
+  #[test]
+  fn test_from_virtual_file() {
+    let fs = MemoryFileSystem::default()
+      .with_file("/src/logo.svg", "<svg></svg>")
+      .with_file("/src/icon.svg", "<svg />");
+
+    let module = ExternalModule::from_virtual_file(&fs, Path::new("/src/logo.svg"))
+      .expect("should read the virtual file");
+    assert_eq!(module.get_external_type(), "asset");
+    assert_eq!(module.user_request, "/src/logo.svg");
+    assert_eq!(
+      module.get_primary_request(),
+      ExternalModule::from_virtual_entry("/src/logo.svg", "<svg></svg>").get_primary_request(),
+      "should version the url with the hash of the file content"
+    );
+    assert!(
+      ExternalModule::from_virtual_file(&fs, Path::new("/src/icon.svg"))
+        .expect("should read the virtual file")
+        .get_primary_request()
+        .is_some_and(|url| url.starts_with("virtual:/src/icon.svg?v="))
+    );
+
+    let missing = ExternalModule::from_virtual_file(&fs, Path::new("/src/missing.svg"))
+      .expect_err("should not create an external for a missing file");
+    assert_eq!(missing.kind(), std::io::ErrorKind::NotFound);
+  }
+
+  #[test]
+  fn test_inline_umd() {
+    assert_eq!(
+      get_source_for_inline_umd("module.exports = 42; // trailing comment"),
+      "(function() {\n\tvar module = { exports: {} }, exports = module.exports, define = undefined;\nmodule.exports = 42; // trailing comment\n\treturn module.exports;\n})()"
+    );
+    let module = create_external_module(
+      "(function (root, factory) {\n  module.exports = factory();\n})(this, function () {\n  return 42;\n});",
+      "inline-umd",
+    );
+    assert!(module.clone_diagnostics().is_empty());
+  }
+
+  #[test]
+  fn test_css_property() {
+    assert_eq!(
+      get_source_for_css_property(
+        "--paint-color; syntax: '<color>'; inherits: false; initial-value: black"
+      )
+      .expect("should be valid"),
+      "@property --paint-color {\n  syntax: \"<color>\";\n  inherits: false;\n  initial-value: black;\n}"
+    );
+    assert_eq!(
+      get_source_for_css_property("--any; syntax: *; inherits: true").expect("should be valid"),
+      "@property --any {\n  syntax: \"*\";\n  inherits: true;\n}"
+    );
+    for request in [
+      "paint-color; syntax: <color>; inherits: false; initial-value: black",
+      "--paint-color; syntax: <color>; initial-value: black",
+      "--paint-color; syntax: <color>; inherits: false",
+      "--paint-color; syntax: <color>; inherits: false; initial: black",
+    ] {
+      assert!(get_source_for_css_property(request).is_err(), "{request}");
+    }
+    assert_eq!(
+      create_external_module(
+        "--paint-color; syntax: <color>; inherits: false",
+        "css-property"
+      )
+      .source_types(),
+      &[SourceType::Css]
+    );
+  }
+
+  #[test]
+  fn test_federation_delegate() {
+    let request = |primary: &str| ExternalRequestValue::new(primary.to_string(), None);
+    let source = get_source_for_federation_delegate(&request("react"));
+    assert!(source.contains("if (__webpack_require__.I) __webpack_require__.I(\"default\");"));
+    assert!(source.contains(
+      "__webpack_require__.S[\"default\"] && __webpack_require__.S[\"default\"][\"react\"]"
+    ));
+    assert!(source.contains("return versions[version].get()();"));
+    let source = get_source_for_federation_delegate(&request("legacy:@scope/pkg"));
+    assert!(source.contains("__webpack_require__.I(\"legacy\")"));
+    assert!(source.contains("__webpack_require__.S[\"legacy\"][\"@scope/pkg\"]"));
+  }
+
+  #[test]
+  fn test_defined_value_to_code() {
+    assert_eq!(
+      defined_value_to_code(r#""\"https://api.example.com\"""#).as_deref(),
+      Some(r#""https://api.example.com""#)
+    );
+    assert_eq!(
+      defined_value_to_code(r#""undefined""#).as_deref(),
+      Some("undefined")
+    );
+    assert_eq!(defined_value_to_code("42").as_deref(), Some("42"));
+    assert_eq!(defined_value_to_code("true").as_deref(), Some("true"));
+    assert_eq!(defined_value_to_code(r#"{"API_URL":"\"/api\""}"#), None);
+  }
+
+  #[test]
+  fn test_alias_request() {
+    let resolve = Resolve {
+      alias: Some(vec![
+        (
+          "react$".to_string(),
+          vec![AliasMap::Path("preact/compat".to_string())],
+        ),
+        (
+          "react-dom".to_string(),
+          vec![AliasMap::Path("preact/compat".to_string())],
+        ),
+        (
+          "lodash".to_string(),
+          vec![AliasMap::Ignore, AliasMap::Path("lodash-es".to_string())],
+        ),
+        (
+          "shim".to_string(),
+          vec![AliasMap::Path("./src/shim.js".to_string())],
+        ),
+        (
+          "polyfill".to_string(),
+          vec![AliasMap::Path("/abs/polyfill.js".to_string())],
+        ),
+      ]),
+      ..Default::default()
+    };
+    let alias = |request| ExternalModule::alias_request(request, &resolve);
+    assert_eq!(alias("react"), "preact/compat");
+    assert_eq!(alias("react/jsx-runtime"), "react/jsx-runtime");
+    assert_eq!(alias("react-dom"), "preact/compat");
+    assert_eq!(alias("react-dom/client"), "preact/compat/client");
+    assert_eq!(alias("react-domain"), "react-domain");
+    assert_eq!(alias("lodash/fp"), "lodash-es/fp");
+    assert_eq!(alias("shim"), "shim");
+    assert_eq!(alias("polyfill"), "polyfill");
+    assert_eq!(alias("vue"), "vue");
+    assert_eq!(
+      ExternalModule::alias_request("react", &Resolve::default()),
+      "react"
+    );
+  }
+
+  #[test]
+  fn test_split_package_request() {
+    let split = ExternalModule::split_package_request;
+    assert_eq!(split("react"), Some(("react", "")));
+    assert_eq!(split("react/jsx-runtime"), Some(("react", "/jsx-runtime")));
+    assert_eq!(split("@scope/pkg"), Some(("@scope/pkg", "")));
+    assert_eq!(split("@scope/pkg/sub"), Some(("@scope/pkg", "/sub")));
+    assert_eq!(split("./lib"), None);
+    assert_eq!(split("/abs/lib"), None);
+    assert_eq!(split("node:path"), None);
+  }
+
+  #[test]
+  fn test_cdn_request() {
+    let manifest = serde_json::json!({ "version": "4.17.21", "main": "./lodash.js" });
+    let cdn = ExternalModule::cdn_request;
+    assert_eq!(
+      cdn("unpkg", "lodash", Some(&manifest)).as_deref(),
+      Some("https://unpkg.com/lodash@4.17.21/lodash.js")
+    );
+    assert_eq!(
+      cdn("unpkg", "lodash/fp", Some(&manifest)).as_deref(),
+      Some("https://unpkg.com/lodash@4.17.21/fp")
+    );
+    assert_eq!(
+      cdn("esm-sh", "lodash", Some(&manifest)).as_deref(),
+      Some("https://esm.sh/lodash@4.17.21")
+    );
+    assert_eq!(
+      cdn("esm-sh", "lodash/fp", Some(&manifest)).as_deref(),
+      Some("https://esm.sh/lodash@4.17.21/fp")
+    );
+    let scoped = serde_json::json!({ "version": "1.0.0" });
+    assert_eq!(
+      cdn("unpkg", "@scope/pkg", Some(&scoped)).as_deref(),
+      Some("https://unpkg.com/@scope/pkg@1.0.0")
+    );
+    assert_eq!(
+      cdn("esm-sh", "lodash", None).as_deref(),
+      Some("https://esm.sh/lodash")
+    );
+    assert_eq!(cdn("esm-sh", "./lodash", Some(&manifest)), None);
+    assert_eq!(cdn("import", "lodash", Some(&manifest)), None);
+  }
+
+  #[test]
+  fn test_normalize_request() {
+    let resolve = Resolve {
+      extensions: Some(vec![".js".to_string(), ".json".to_string()]),
+      ..Default::default()
+    };
+    let normalize = |request| ExternalModule::normalize_request(request, &resolve);
+    assert_eq!(normalize("react"), "react");
+    assert_eq!(normalize("react/"), "react");
+    assert_eq!(normalize("react//"), "react");
+    assert_eq!(normalize("react/index.js"), "react/index");
+    assert_eq!(normalize("lodash/fp.js/"), "lodash/fp");
+    assert_eq!(normalize("@scope/pkg/"), "@scope/pkg");
+    assert_eq!(normalize("@scope/pkg/lib.js"), "@scope/pkg/lib");
+    assert_eq!(normalize("chart.js"), "chart.js");
+    assert_eq!(normalize("@scope/pkg.js"), "@scope/pkg.js");
+    assert_eq!(
+      normalize("https://cdn.example.com/react.js"),
+      "https://cdn.example.com/react.js"
+    );
+    assert_eq!(normalize("node:path/"), "node:path/");
+    assert_eq!(normalize("./lib/"), "./lib/");
+    assert_eq!(normalize("/"), "/");
+
+    let resolve = Resolve {
+      extensions: Some(vec![".ts".to_string()]),
+      ..Default::default()
+    };
+    assert_eq!(
+      ExternalModule::normalize_request("react/index.js", &resolve),
+      "react/index.js"
+    );
+    assert_eq!(
+      ExternalModule::normalize_request("react/index.js", &Resolve::default()),
+      "react/index.js"
+    );
+  }
+
+  #[test]
+  fn test_fallback_chain() {
+    let link = |external_type: &str, request: &str| {
+      (
+        external_type.to_string(),
+        ExternalRequestValue::new(request.to_string(), None),
+      )
+    };
+    let self_object = "self".to_string();
+    assert_eq!(
+      get_source_for_fallback_chain(
+        &[link("commonjs", "lodash"), link("window", "_")],
+        &self_object
+      )
+      .unwrap(),
+      r#"typeof require !== "undefined" ? require("lodash") : window._"#
+    );
+    assert_eq!(
+      get_source_for_fallback_chain(
+        &[
+          link("commonjs", "lodash"),
+          link("var", "_"),
+          link("global", "lodash")
+        ],
+        &"globalThis".to_string()
+      )
+      .unwrap(),
+      r#"typeof require !== "undefined" ? require("lodash") : typeof _ !== "undefined" ? _ : globalThis.lodash"#
+    );
+    assert!(get_source_for_fallback_chain(
+      &[link("import", "lodash"), link("window", "_")],
+      &self_object
+    )
+    .is_err());
+  }
+
+  #[test]
+  fn test_async_init() {
+    assert_eq!(
+      get_source_with_async_init(
+        "await loadFlags();",
+        "module.exports = require(\"lodash\");"
+      ),
+      "module.exports = (async function(module) {\nawait loadFlags();\nmodule.exports = require(\"lodash\");\nreturn module.exports;\n})({ exports: {} });"
+    );
+  }
+
+  #[test]
+  fn test_is_subpath_exported() {
+    let exports = serde_json::json!({
+      ".": "./index.js",
+      "./sub": "./sub.js",
+      "./features/*.js": "./src/features/*.js",
+      "./features/internal/*": null,
+      "./legacy/": "./legacy/"
+    });
+    assert!(is_subpath_exported(&exports, "./sub"));
+    assert!(is_subpath_exported(&exports, "./features/a.js"));
+    assert!(is_subpath_exported(&exports, "./legacy/b.js"));
+    assert!(!is_subpath_exported(&exports, "./features/internal/c"));
+    assert!(!is_subpath_exported(&exports, "./sub.js"));
+    assert!(!is_subpath_exported(&exports, "./package.json"));
+    assert!(!is_subpath_exported(
+      &serde_json::json!({ "import": "./index.mjs", "require": "./index.cjs" }),
+      "./sub"
+    ));
+  }
+
+  #[test]
+  fn test_source_url() {
+    assert_eq!(
+      create_external_module("React@https://cdn.example.com/react.js", "script").get_source_url(),
+      Some("https://cdn.example.com/react.js")
+    );
+    assert_eq!(
+      create_external_module("Lib@https://cdn.example.com/lib.iife.js", "import-script")
+        .get_source_url(),
+      Some("https://cdn.example.com/lib.iife.js")
+    );
+    assert_eq!(
+      create_external_module("https://cdn.example.com/lib.mjs", "import").get_source_url(),
+      Some("https://cdn.example.com/lib.mjs")
+    );
+    assert_eq!(
+      create_external_module("react", "var").get_source_url(),
+      None
+    );
+    assert_eq!(
+      create_external_module("https://cdn.example.com/paint.js", "import-css-worklet")
+        .get_source_url(),
+      Some("https://cdn.example.com/paint.js")
+    );
+  }
+
+  #[test]
+  fn test_version_check() {
+    let check = get_version_check("module.exports", "^18.2.0", "react");
+    assert!(check.contains(r#"of external module " + "react" + " does not satisfy"#));
+    assert!(check.ends_with(r#"})(module.exports && module.exports.version, "^18.2.0");"#));
+    let check = get_version_check("exports", "^18.2.0", "react");
+    assert!(check.ends_with(r#"})(exports && exports.version, "^18.2.0");"#));
+  }
+
+  #[test]
+  fn test_request_transformer() {
+    let module = create_external_module("react@18", "script").with_request_transformer(|request| {
+      format!("React@https://cdn.example.com/{request}.js?v=a1b2c3")
+    });
+    assert_eq!(
+      module.get_source_url(),
+      Some("https://cdn.example.com/react@18.js?v=a1b2c3")
+    );
+    assert_eq!(
+      module.identifier(),
+      create_external_module(
+        "React@https://cdn.example.com/react@18.js?v=a1b2c3",
+        "script"
+      )
+      .identifier()
+    );
+    assert_eq!(module.user_request, "react@18");
+  }
+
+  #[test]
+  fn test_validate_request() {
+    assert!(ExternalModule::validate_request("react").is_ok());
+    assert!(ExternalModule::validate_request("https://cdn.example.com/lib.mjs").is_ok());
+    for request in ["", "react\n", "re\r\nact", "react\u{2028}", "\0react"] {
+      assert!(
+        ExternalModule::validate_request(request).is_err(),
+        "{request:?}"
+      );
+    }
+    assert!(create_external_module("react", "var")
+      .clone_diagnostics()
+      .is_empty());
+    assert_eq!(
+      create_external_module("react\n;alert(1)", "var")
+        .clone_diagnostics()
+        .len(),
+      1
+    );
+  }
+
+  #[test]
+  fn test_hot_update_hash() {
+    let hash =
+      create_external_module("https://cdn.example.com/v1/lib.mjs", "import").hot_update_hash();
+    assert_eq!(
+      hash,
+      create_external_module("https://cdn.example.com/v1/lib.mjs", "import").hot_update_hash()
+    );
+    assert_ne!(
+      hash,
+      create_external_module("https://cdn.example.com/v2/lib.mjs", "import").hot_update_hash()
+    );
+    assert_ne!(
+      hash,
+      create_external_module("https://cdn.example.com/v1/lib.mjs", "module").hot_update_hash()
+    );
+  }
+}
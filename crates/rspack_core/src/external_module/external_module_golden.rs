@@ -0,0 +1,226 @@
+//! Golden-file tests of the generated stubs, one `.golden` file per external type.
+//!
+//! Run with `UPDATE_GOLDEN=1` to regenerate the files after an intended change.
+
+use std::path::PathBuf;
+
+use super::*;
+
+fn assert_golden(name: &str, actual: &str) {
+  let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    .join("src/external_module/golden")
+    .join(format!("{name}.golden"));
+  if std::env::var_os("UPDATE_GOLDEN").is_some() {
+    std::fs::write(&path, actual).expect("should write golden file");
+    return;
+  }
+  let expected = std::fs::read_to_string(&path)
+    .unwrap_or_else(|_| panic!("missing golden file {}", path.display()));
+  assert_eq!(
+    actual, expected,
+    "{name}.golden is out of date, run with UPDATE_GOLDEN=1 to update it"
+  );
+}
+
+fn request(primary: &str, rest: Option<&[&str]>) -> ExternalRequestValue {
+  ExternalRequestValue::new(
+    primary.to_string(),
+    rest.map(|rest| rest.iter().map(|s| (*s).to_string()).collect()),
+  )
+}
+
+/// The requests every external type is rendered with, including unicode and quotes.
+fn cases() -> Vec<(&'static str, ExternalRequestValue)> {
+  vec![
+    ("simple", request("lodash", None)),
+    ("property path", request("lodash", Some(&["fp", "map"]))),
+    ("scoped", request("@scope/pkg", None)),
+    ("unicode", request("пакет-ü", None)),
+    ("quotes", request("a\"b'c", None)),
+  ]
+}
+
+fn render(render_case: impl Fn(&ExternalRequestValue) -> String) -> String {
+  let mut rendered = String::new();
+  for (name, request) in cases() {
+    rendered.push_str(&format!("// {name}\n{}\n", render_case(&request)));
+  }
+  rendered
+}
+
+#[test]
+fn commonjs() {
+  assert_golden(
+    "commonjs",
+    &render(|request| format!("module.exports = {};", get_source_for_commonjs(request))),
+  );
+}
+
+#[test]
+fn commonjs_esmodule_interop() {
+  assert_golden(
+    "commonjs_esmodule_interop",
+    &render(|request| {
+      format!(
+        "module.exports = {};",
+        get_source_for_commonjs_with_esmodule_interop(request)
+      )
+    }),
+  );
+}
+
+#[test]
+fn window() {
+  let external_type = "window".to_string();
+  assert_golden(
+    "window",
+    &render(|request| {
+      format!(
+        "module.exports = {};",
+        get_source_for_global_variable_external(request, &external_type)
+      )
+    }),
+  );
+}
+
+#[test]
+fn self_() {
+  let external_type = "self".to_string();
+  assert_golden(
+    "self",
+    &render(|request| {
+      format!(
+        "module.exports = {};",
+        get_source_for_global_variable_external(request, &external_type)
+      )
+    }),
+  );
+}
+
+#[test]
+fn window_csp_safe() {
+  let external_type = "window".to_string();
+  assert_golden(
+    "window_csp_safe",
+    &render(|request| {
+      format!(
+        "module.exports = {};",
+        get_source_for_csp_safe_global_variable_external(request, &external_type)
+      )
+    }),
+  );
+}
+
+#[test]
+fn global() {
+  let global_object = "globalThis".to_string();
+  assert_golden(
+    "global",
+    &render(|request| {
+      format!(
+        "module.exports = {};",
+        get_source_for_global_variable_external(request, &global_object)
+      )
+    }),
+  );
+}
+
+#[test]
+fn this() {
+  let external_type = "this".to_string();
+  assert_golden(
+    "this",
+    &render(|request| {
+      format!(
+        "module.exports = (function() {{ return {}; }}());",
+        get_source_for_global_variable_external(request, &external_type)
+      )
+    }),
+  );
+}
+
+#[test]
+fn this_strict() {
+  assert_golden(
+    "this_strict",
+    &render(|request| {
+      format!(
+        "module.exports = {};",
+        get_source_for_strict_this_external(request)
+      )
+    }),
+  );
+}
+
+// The request is embedded as is, so requests that are not expressions, e.g. `@scope/pkg`,
+// produce invalid code. This is the same in webpack.
+#[test]
+fn var() {
+  assert_golden(
+    "var",
+    &render(|request| {
+      format!(
+        "module.exports = {};",
+        get_source_for_default_case(false, request)
+      )
+    }),
+  );
+}
+
+#[test]
+fn fallback_chain() {
+  let global_object = "globalThis".to_string();
+  assert_golden(
+    "fallback_chain",
+    &render(|request| {
+      let chain = [
+        ("commonjs".to_string(), request.clone()),
+        (
+          "var".to_string(),
+          ExternalRequestValue::new("_".to_string(), None),
+        ),
+        ("global".to_string(), request.clone()),
+      ];
+      format!(
+        "module.exports = {};",
+        get_source_for_fallback_chain(&chain, &global_object).expect("should render chain")
+      )
+    }),
+  );
+}
+
+#[test]
+fn async_init() {
+  assert_golden(
+    "async_init",
+    &render(|request| {
+      get_source_with_async_init(
+        "await loadFlags();",
+        &format!("module.exports = {};", get_source_for_commonjs(request)),
+      )
+    }),
+  );
+}
+
+#[test]
+fn version_check() {
+  assert_golden(
+    "version_check",
+    &render(|request| get_version_check("^18.0.0", request.primary())),
+  );
+}
+
+#[test]
+fn empty_request() {
+  let module = ExternalModule::new(
+    ExternalRequest::Single(request("", None)),
+    "commonjs".to_string(),
+    String::new(),
+    DependencyMeta {
+      external_type: None,
+    },
+  );
+  let diagnostics = module.clone_diagnostics();
+  assert_eq!(diagnostics.len(), 1);
+  assert!(diagnostics[0].message().contains("must not be empty"));
+}
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use proptest::prelude::*;
+use swc_core::{
+  common::{FileName, SourceMap},
+  ecma::{
+    ast::EsVersion,
+    parser::{parse_file_as_script, Syntax},
+  },
+};
+
+use super::*;
+
+fn is_valid_script(source: &str) -> bool {
+  let cm: Arc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(
+    Arc::new(FileName::Custom("external.js".into())),
+    source.to_string(),
+  );
+  let mut errors = vec![];
+  parse_file_as_script(&fm, Syntax::default(), EsVersion::EsNext, None, &mut errors).is_ok()
+    && errors.is_empty()
+}
+
+fn request_value() -> impl Strategy<Value = ExternalRequestValue> {
+  (
+    any::<String>(),
+    proptest::option::of(proptest::collection::vec(any::<String>(), 1..4)),
+  )
+    .prop_map(|(primary, rest)| ExternalRequestValue::new(primary, rest))
+}
+
+fn global_external_type() -> impl Strategy<Value = ExternalType> {
+  prop_oneof![
+    Just("window".to_string()),
+    Just("self".to_string()),
+    Just("globalThis".to_string()),
+  ]
+}
+
+proptest! {
+  #[test]
+  fn commonjs_source_is_valid_script(request in request_value()) {
+    let source = format!("module.exports = {};", get_source_for_commonjs(&request));
+    prop_assert!(is_valid_script(&source), "invalid source: {}", source);
+  }
+
+  #[test]
+  fn global_variable_source_is_valid_script(
+    request in request_value(),
+    external_type in global_external_type(),
+  ) {
+    let source = format!(
+      "module.exports = {};",
+      get_source_for_global_variable_external(&request, &external_type)
+    );
+    prop_assert!(is_valid_script(&source), "invalid source: {}", source);
+  }
+
+  #[test]
+  fn default_case_source_is_valid_script(
+    primary in "[A-Z_$][a-zA-Z0-9_$]*",
+    rest in proptest::option::of(proptest::collection::vec(any::<String>(), 1..4)),
+  ) {
+    let request = ExternalRequestValue::new(primary, rest);
+    let source = format!("module.exports = {};", get_source_for_default_case(false, &request));
+    prop_assert!(is_valid_script(&source), "invalid source: {}", source);
+  }
+
+  #[test]
+  fn canonicalize_request_is_idempotent(request in any::<String>()) {
+    let canonical = canonicalize_request(&request);
+    prop_assert!(request.ends_with(canonical.as_ref()));
+    prop_assert_eq!(canonicalize_request(&canonical), canonical.clone());
+  }
+
+  #[test]
+  fn identifier_is_stable_across_redundant_prefix(request in "[a-z@][a-z0-9/._-]{0,20}") {
+    let create = |request: String| {
+      ExternalModule::new(
+        ExternalRequest::Single(ExternalRequestValue::new(request.clone(), None)),
+        "commonjs".to_string(),
+        request,
+        DependencyMeta {
+          external_type: None,
+        },
+      )
+    };
+    prop_assert_eq!(
+      create(format!("./{request}")).identifier(),
+      create(request).identifier()
+    );
+  }
+}
@@ -12,6 +12,7 @@ use rspack_error::Result;
 use rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt};
 use rspack_util::ext::{DynHash, IntoAny};
 use rustc_hash::FxHasher;
+use smallvec::SmallVec;
 use swc_core::ecma::atoms::Atom;
 
 use crate::{
@@ -201,13 +202,17 @@ pub enum InitFragmentStage {
 }
 
 /// InitFragment.addToSource
-pub fn render_init_fragments<C: InitFragmentRenderContext>(
+pub fn render_init_fragments<C, F>(
   source: BoxSource,
-  mut fragments: Vec<Box<dyn InitFragment<C>>>,
+  mut fragments: F,
   context: &mut C,
-) -> Result<BoxSource> {
+) -> Result<BoxSource>
+where
+  C: InitFragmentRenderContext,
+  F: AsMut<[Box<dyn InitFragment<C>>]> + IntoIterator<Item = Box<dyn InitFragment<C>>>,
+{
   // here use sort_by_key because need keep order equal stage fragments
-  fragments.sort_by(|a, b| {
+  fragments.as_mut().sort_by(|a, b| {
     let stage = a.stage().cmp(&b.stage());
     if !stage.is_eq() {
       return stage;
@@ -254,7 +259,46 @@ pub type BoxInitFragment<C> = Box<dyn InitFragment<C>>;
 pub type BoxModuleInitFragment<'a> = BoxInitFragment<GenerateContext<'a>>;
 pub type BoxChunkInitFragment = BoxInitFragment<ChunkRenderContext>;
 pub type ModuleInitFragments<'a> = Vec<BoxModuleInitFragment<'a>>;
-pub type ChunkInitFragments = Vec<BoxChunkInitFragment>;
+/// Most modules contribute no more than a few chunk init fragments, keep them inline
+pub type ChunkInitFragments = SmallVec<[BoxChunkInitFragment; 4]>;
+
+pub trait ChunkInitFragmentsExt {
+  /// Add a fragment, merging it into an already added fragment with the same key, stage and
+  /// position, so that e.g. many imports of the same external stay one inline fragment
+  fn add(&mut self, fragment: BoxChunkInitFragment);
+
+  fn add_all(&mut self, fragments: impl IntoIterator<Item = BoxChunkInitFragment>) {
+    for fragment in fragments {
+      self.add(fragment);
+    }
+  }
+}
+
+impl ChunkInitFragmentsExt for ChunkInitFragments {
+  fn add(&mut self, fragment: BoxChunkInitFragment) {
+    // render_init_fragments merges these keys from a single fragment only
+    if matches!(
+      fragment.key(),
+      InitFragmentKey::ESMCompatibility | InitFragmentKey::Unique(_)
+    ) {
+      self.push(fragment);
+      return;
+    }
+    let index = self.iter().position(|existing| {
+      existing.key() == fragment.key()
+        && existing.stage() == fragment.stage()
+        && existing.position() == fragment.position()
+    });
+    if let Some(index) = index {
+      // same slot after the stable sort in render_init_fragments, so merging early renders the same
+      let key = fragment.key().clone();
+      let existing = self.remove(index);
+      self.insert(index, key.merge_fragments(vec![existing, fragment]));
+    } else {
+      self.push(fragment);
+    }
+  }
+}
 
 impl InitFragmentRenderContext for GenerateContext<'_> {
   fn add_runtime_requirements(&mut self, requirement: RuntimeGlobals) {
@@ -683,3 +727,68 @@ impl<C: InitFragmentRenderContext> InitFragment<C> for ExternalModuleInitFragmen
     &self.key
   }
 }
+
+#[cfg(test)]
+mod test {
+  use rspack_sources::Source;
+
+  use super::*;
+
+  fn external_fragment(name: &str) -> BoxChunkInitFragment {
+    ExternalModuleInitFragment::new(
+      "node:module".to_string(),
+      vec![(name.to_string(), name.to_string())],
+      None,
+      InitFragmentStage::StageConstants,
+      0,
+    )
+    .boxed()
+  }
+
+  fn render(fragments: ChunkInitFragments) -> String {
+    render_init_fragments(
+      RawSource::from("").boxed(),
+      fragments,
+      &mut ChunkRenderContext,
+    )
+    .expect("should render")
+    .source()
+    .to_string()
+  }
+
+  #[test]
+  fn test_add_merges_fragments_with_same_key() {
+    let mut pushed = ChunkInitFragments::default();
+    let mut added = ChunkInitFragments::default();
+    for i in 0..1000 {
+      let fragment = external_fragment(&format!("specifier{}", i % 10));
+      pushed.push(fragment.clone());
+      added.add(fragment);
+    }
+    assert_eq!(added.len(), 1);
+    assert!(!added.spilled());
+    assert_eq!(render(added), render(pushed));
+  }
+
+  #[test]
+  fn test_add_keeps_different_keys() {
+    let mut fragments = ChunkInitFragments::default();
+    fragments.add_all([
+      external_fragment("createRequire"),
+      NormalInitFragment::new(
+        "var a = 1;\n".to_string(),
+        InitFragmentStage::StageConstants,
+        0,
+        InitFragmentKey::unique(),
+        None,
+      )
+      .boxed(),
+      external_fragment("builtinModules"),
+    ]);
+    assert_eq!(fragments.len(), 2);
+    assert_eq!(
+      render(fragments),
+      "import {builtinModules, createRequire} from \"node:module\";\nvar a = 1;\n"
+    );
+  }
+}
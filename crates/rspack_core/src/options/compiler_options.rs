@@ -1,3 +1,5 @@
+use rustc_hash::FxHashMap as HashMap;
+
 use crate::{
   CacheOptions, Context, Experiments, Mode, ModuleOptions, NodeOption, Optimization, OutputOptions,
   Resolve, SnapshotOptions, StatsOptions,
@@ -19,6 +21,12 @@ pub struct CompilerOptions {
   pub optimization: Optimization,
   pub profile: bool,
   pub bail: bool,
+  /// Chunk name to external request to the request that replaces it in that chunk, e.g. to load
+  /// externals of a region specific entry from another CDN host.
+  pub per_chunk_external_overrides: HashMap<String, HashMap<String, String>>,
+  /// Warn when an external such as `pkg/sub` requests a subpath that is not listed in the
+  /// `exports` field of the package's `package.json`.
+  pub validate_subpath_exports: bool,
   pub __references: References,
 }
 
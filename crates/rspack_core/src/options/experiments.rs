@@ -1,3 +1,5 @@
+use rustc_hash::FxHashMap as HashMap;
+
 use crate::unaffected_cache::IncrementalPasses;
 
 #[derive(Debug)]
@@ -6,6 +8,26 @@ pub struct Experiments {
   pub incremental: IncrementalPasses,
   pub top_level_await: bool,
   pub rspack_future: RspackFuture,
+  pub externals: ExternalsExperiments,
+}
+
+#[derive(Debug, Default)]
+pub struct ExternalsExperiments {
+  /// Infers the type of an external without a type prefix from the file extension of its
+  /// request instead of using `externalsType`.
+  pub type_inference: bool,
+  /// Subresource integrity hashes of `script` externals keyed by the request as written in
+  /// the source code, set on the `<script>` tag that loads the external.
+  pub integrity: HashMap<String, String>,
+  /// Runtimes externals are loaded in keyed by the request as written in the source code, the
+  /// other runtimes skip loading them.
+  pub runtime: HashMap<String, Vec<String>>,
+  /// Versions externals are expected to satisfy keyed by the request as written in the source
+  /// code, checked against the `version` export of the external at runtime.
+  pub expected_version: HashMap<String, String>,
+  /// Reads the globals of `window` and `self` externals through a guarded lookup that yields
+  /// `undefined` when the global is missing.
+  pub csp_safe: bool,
 }
 
 #[allow(clippy::empty_structs_with_brackets)]
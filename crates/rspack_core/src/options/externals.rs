@@ -19,6 +19,20 @@ pub type ExternalItemObject = HashMap<String, ExternalItemValue>;
 
 pub struct ContextInfo {
   pub issuer: String,
+  pub issuer_layer: Option<String>,
+}
+
+pub struct ExternalItemResolveData {
+  pub request: String,
+  pub context: String,
+  pub dependency_type: String,
+  pub context_info: ContextInfo,
+  /// The request without its query and fragment.
+  pub path: String,
+  /// The query of the request including the leading `?`, or an empty string.
+  pub query: String,
+  /// The fragment of the request including the leading `#`, or an empty string.
+  pub fragment: String,
 }
 
 pub struct ExternalItemFnCtx {
@@ -26,6 +40,7 @@ pub struct ExternalItemFnCtx {
   pub context: String,
   pub dependency_type: String,
   pub context_info: ContextInfo,
+  pub resolve_data: ExternalItemResolveData,
 }
 
 pub struct ExternalItemFnResult {
@@ -250,6 +250,11 @@ bitflags! {
     const RSPACK_UNIQUE_ID = 1 << 65;
 
     const HAS_FETCH_PRIORITY = 1 << 66;
+
+    /**
+     * the Module Federation runtime
+     */
+    const FEDERATION = 1 << 67;
   }
 }
 
@@ -337,6 +342,7 @@ impl RuntimeGlobals {
       R::HAS_CSS_MODULES => "has css modules",
 
       R::HAS_FETCH_PRIORITY => "has fetch priority",
+      R::FEDERATION => "__webpack_require__.federation",
       _ => unreachable!(),
     }
   }
@@ -367,6 +373,8 @@ mod test {
     assert_eq!(format!("{flags}"), "__webpack_require__.p");
     let flags = RuntimeGlobals::GET_CHUNK_CSS_FILENAME;
     assert_eq!(format!("{flags}"), "__webpack_require__.k");
+    let flags = RuntimeGlobals::FEDERATION;
+    assert_eq!(format!("{flags}"), "__webpack_require__.federation");
   }
 
   #[test]
@@ -699,6 +699,12 @@ impl Stats<'_> {
       r#type: "module",
       module_type: *module.module_type(),
       layer: module.get_layer().map(|layer| layer.into()),
+      external_type: module
+        .as_external_module()
+        .map(|external_module| external_module.get_external_type().as_str()),
+      external_request: module
+        .as_external_module()
+        .and_then(|external_module| external_module.get_primary_request()),
       size: module.size(None, self.compilation),
       sizes,
       built,
@@ -1007,6 +1013,8 @@ impl Stats<'_> {
       r#type: "module",
       module_type: module.module_type,
       layer: None,
+      external_type: None,
+      external_request: None,
       size: module.size,
       sizes: vec![StatsSourceTypeSize {
         source_type: SourceType::Custom("runtime".into()),
@@ -1117,6 +1125,8 @@ impl Stats<'_> {
       r#type: "module",
       module_type: *module.module_type(),
       layer: module.get_layer().map(|layer| layer.into()),
+      external_type: None,
+      external_request: None,
       size,
       sizes: vec![StatsSourceTypeSize {
         source_type: SourceType::Custom("runtime".into()),
@@ -135,6 +135,8 @@ pub struct StatsModule<'s> {
   pub r#type: &'static str,
   pub module_type: ModuleType,
   pub layer: Option<Cow<'s, str>>,
+  pub external_type: Option<&'s str>,
+  pub external_request: Option<&'s str>,
   pub identifier: Option<ModuleIdentifier>,
   pub name: Option<Cow<'s, str>>,
   pub name_for_condition: Option<String>,
@@ -209,7 +209,13 @@ impl SourceMapDevToolPlugin {
       .filter_map(|(_file, _asset, source_map)| source_map.as_ref())
       .flat_map(|source_map| source_map.sources())
       .map(|source| {
-        let module_or_source = if let Some(stripped) = source.strip_prefix("webpack://") {
+        let module_graph = compilation.get_module_graph();
+        let module_or_source = if let Some(module) =
+          module_graph.module_by_identifier(&ModuleIdentifier::from(source.as_ref()))
+          && module.as_external_module().is_some()
+        {
+          ModuleOrSource::Module(module.identifier())
+        } else if let Some(stripped) = source.strip_prefix("webpack://") {
           let source = make_paths_absolute(compilation.options.context.as_str(), stripped);
           let identifier = ModuleIdentifier::from(source.as_str());
           match compilation
@@ -324,21 +330,42 @@ impl SourceMapDevToolPlugin {
       used_names_set.insert(source_name);
     }
 
+    let module_graph = compilation.get_module_graph();
     for (filename, _asset, source_map) in mapped_sources.iter_mut() {
       if let Some(source_map) = source_map {
         source_map.set_file(Some(filename.clone()));
 
         let sources = source_map.sources_mut();
-        for source in sources {
+        let mut remote_sources = vec![];
+        for (index, source) in sources.iter_mut().enumerate() {
           let module_or_source = source_map_modules
             .get(source.as_ref())
             .expect("expected a module or source");
+          // Externals loaded from a CDN are referenced by their url, so debuggers can fetch them.
+          // The url is absolute and would not be relative to a `sourceRoot`.
+          if self.source_root.is_none()
+            && let ModuleOrSource::Module(identifier) = module_or_source
+            && let Some(url) = module_graph
+              .module_by_identifier(identifier)
+              .and_then(|module| module.as_external_module())
+              .and_then(|module| module.get_source_url())
+          {
+            *source = Cow::from(url.to_string());
+            remote_sources.push(index);
+            continue;
+          }
           let source_name = module_to_source_name
             .get(module_or_source)
             .expect("expected a filename at the given index but found None")
             .clone();
           *source = Cow::from(source_name);
         }
+        let sources_content = source_map.sources_content_mut();
+        for index in remote_sources {
+          if let Some(content) = sources_content.get_mut(index) {
+            *content = Default::default();
+          }
+        }
         if self.no_sources {
           for content in source_map.sources_content_mut() {
             *content = Default::default();
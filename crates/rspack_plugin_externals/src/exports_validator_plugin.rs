@@ -0,0 +1,226 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use regex::Regex;
+use rspack_collections::Identifiable;
+use rspack_core::{
+  ApplyContext, Compilation, CompilationFinishModules, CompilerOptions, DependencyType,
+  ExternalRequest, Plugin, PluginContext,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_fs::ReadableFileSystem;
+use rspack_hook::{plugin, plugin_hook};
+
+/// Following `module.exports = require("./cjs/lib.js")` and `export * from "./lib"`
+/// re-exports deeper than this gives up on the package.
+const MAX_REEXPORT_DEPTH: usize = 8;
+
+static EXPORT_NAME_REGEXPS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+  [
+    r"(?:module\.)?exports\.([A-Za-z_$][\w$]*)\s*=[^=]",
+    r#"(?:module\.)?exports\[\s*["']([^"']+)["']\s*\]\s*=[^=]"#,
+    r#"Object\.defineProperty\(\s*(?:module\.)?exports\s*,\s*["']([^"']+)["']"#,
+    r"export\s+(?:async\s+)?(?:function\s*\*?|class|const|let|var)\s*([A-Za-z_$][\w$]*)",
+  ]
+  .into_iter()
+  .map(|source| Regex::new(source).expect("Invalid regex"))
+  .collect()
+});
+
+static EXPORT_LIST_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"(?:export|module\.exports\s*=)\s*\{([^}]*)\}").expect("Invalid regex")
+});
+
+static MODULE_EXPORTS_REGEXP: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"module\.exports\s*=\s*([^\s;]+)").expect("Invalid regex"));
+
+static REEXPORT_REGEXP: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(
+    r#"(?:module\.exports\s*=\s*require\(\s*|export\s*\*\s*from\s*)["'](\.{1,2}/[^"']+)["']"#,
+  )
+  .expect("Invalid regex")
+});
+
+/// Warns about named imports of an external package that its entry file does not export,
+/// e.g. `import { useHook } from "react"` when `react` is a `commonjs` external.
+///
+/// The export names are collected by scanning the entry file found through the `main` field
+/// of the package's `package.json` in `node_modules`. Packages whose exports can't be told
+/// apart this way, e.g. `module.exports = factory()`, are not checked.
+#[plugin]
+#[derive(Debug, Default)]
+pub struct ExternalExportsValidatorPlugin;
+
+#[plugin_hook(CompilationFinishModules for ExternalExportsValidatorPlugin)]
+async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+  let module_graph = compilation.get_module_graph();
+  let fs = compilation.input_filesystem.as_ref();
+  let context: &Path = compilation.options.context.as_ref();
+  let mut known_exports: HashMap<String, Option<BTreeSet<String>>> = HashMap::new();
+  let mut diagnostics = vec![];
+
+  let mut external_modules = module_graph
+    .modules()
+    .values()
+    .filter_map(|module| module.as_external_module())
+    .collect::<Vec<_>>();
+  external_modules.sort_by_key(|module| module.identifier());
+
+  for external_module in external_modules {
+    if !matches!(
+      external_module.get_external_type().as_str(),
+      "commonjs"
+        | "commonjs2"
+        | "commonjs-module"
+        | "commonjs-static"
+        | "node-commonjs"
+        | "module"
+        | "import"
+    ) {
+      continue;
+    }
+    let ExternalRequest::Single(request) = &external_module.request else {
+      continue;
+    };
+    if request.rest().is_some() || !is_package_name(request.primary()) {
+      continue;
+    }
+    let package = request.primary();
+    let Some(exports) = known_exports
+      .entry(package.to_string())
+      .or_insert_with(|| collect_package_exports(fs, context, package))
+    else {
+      continue;
+    };
+
+    let mut reported = BTreeSet::new();
+    for connection in module_graph.get_incoming_connections(&external_module.identifier()) {
+      let Some(dependency) = module_graph.dependency_by_id(&connection.dependency_id) else {
+        continue;
+      };
+      if *dependency.dependency_type() != DependencyType::EsmImportSpecifier {
+        continue;
+      }
+      let Some(name) = dependency.get_ids(&module_graph).into_iter().next() else {
+        continue;
+      };
+      if name == "default" || name == "__esModule" || exports.contains(name.as_str()) {
+        continue;
+      }
+      if reported.insert((connection.original_module_identifier, name.clone())) {
+        diagnostics.push(
+          Diagnostic::warn(
+            "Unknown external export".to_string(),
+            format!("export '{name}' was not found in external '{package}'"),
+          )
+          .with_module_identifier(connection.original_module_identifier),
+        );
+      }
+    }
+  }
+
+  compilation.extend_diagnostics(diagnostics);
+  Ok(())
+}
+
+impl Plugin for ExternalExportsValidatorPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.ExternalExportsValidatorPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .finish_modules
+      .tap(finish_modules::new(self));
+    Ok(())
+  }
+}
+
+/// `react` and `@scope/pkg`, subpaths such as `react/jsx-runtime` and urls are not checked.
+fn is_package_name(request: &str) -> bool {
+  let segments = if request.starts_with('@') { 2 } else { 1 };
+  !request.is_empty()
+    && !request.starts_with('.')
+    && !request.contains(':')
+    && request.split('/').count() == segments
+}
+
+fn collect_package_exports(
+  fs: &dyn ReadableFileSystem,
+  context: &Path,
+  package: &str,
+) -> Option<BTreeSet<String>> {
+  let package_dir = context
+    .ancestors()
+    .map(|dir| dir.join("node_modules").join(package))
+    .find(|dir| fs.metadata(&dir.join("package.json")).is_ok())?;
+  let manifest = fs.read(&package_dir.join("package.json")).ok()?;
+  let manifest: serde_json::Value = serde_json::from_slice(&manifest).ok()?;
+  let main = manifest
+    .get("main")
+    .and_then(|main| main.as_str())
+    .unwrap_or("index.js");
+  let mut exports = BTreeSet::new();
+  collect_file_exports(fs, &package_dir.join(main), &mut exports, 0)?;
+  Some(exports)
+}
+
+/// Returns `None` when the exports of the file can't be statically collected.
+fn collect_file_exports(
+  fs: &dyn ReadableFileSystem,
+  path: &Path,
+  exports: &mut BTreeSet<String>,
+  depth: usize,
+) -> Option<()> {
+  if depth > MAX_REEXPORT_DEPTH {
+    return None;
+  }
+  let path = resolve_file(fs, path)?;
+  let source = String::from_utf8(fs.read(&path).ok()?).ok()?;
+
+  for captures in MODULE_EXPORTS_REGEXP.captures_iter(&source) {
+    let value = &captures[1];
+    if !value.starts_with('{') && !value.starts_with("require(") {
+      return None;
+    }
+  }
+  for regexp in EXPORT_NAME_REGEXPS.iter() {
+    exports.extend(
+      regexp
+        .captures_iter(&source)
+        .map(|captures| captures[1].to_string()),
+    );
+  }
+  for captures in EXPORT_LIST_REGEXP.captures_iter(&source) {
+    exports.extend(captures[1].split(',').filter_map(|item| {
+      let item = item.split(':').next()?.trim();
+      let name = item.rsplit(" as ").next()?.trim();
+      (!name.is_empty()).then(|| name.to_string())
+    }));
+  }
+  if source.contains("export default") {
+    exports.insert("default".to_string());
+  }
+  let dir = path.parent()?;
+  for captures in REEXPORT_REGEXP.captures_iter(&source) {
+    collect_file_exports(fs, &dir.join(&captures[1]), exports, depth + 1)?;
+  }
+  Some(())
+}
+
+fn resolve_file(fs: &dyn ReadableFileSystem, path: &Path) -> Option<PathBuf> {
+  let mut candidates = vec![path.to_path_buf()];
+  for extension in ["js", "cjs", "mjs"] {
+    let mut file = path.as_os_str().to_owned();
+    file.push(".");
+    file.push(extension);
+    candidates.push(PathBuf::from(file));
+  }
+  candidates.push(path.join("index.js"));
+  candidates
+    .into_iter()
+    .find(|candidate| fs.metadata(candidate).is_ok_and(|metadata| metadata.is_file()))
+}
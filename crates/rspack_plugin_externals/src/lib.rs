@@ -1,11 +1,17 @@
 #![feature(let_chains)]
 
 mod electron_target_plugin;
+mod exports_validator_plugin;
 mod http_externals_plugin;
+mod mock_externals_plugin;
 mod node_target_plugin;
 mod plugin;
+mod typescript_paths_externals_plugin;
 
 pub use electron_target_plugin::{electron_target_plugin, ElectronTargetContext};
+pub use exports_validator_plugin::ExternalExportsValidatorPlugin;
 pub use http_externals_plugin::http_externals_rspack_plugin;
+pub use mock_externals_plugin::MockExternalsPlugin;
 pub use node_target_plugin::node_target_plugin;
-pub use plugin::ExternalsPlugin;
+pub use plugin::{infer_external_type_from_request, ExternalRequestTransformer, ExternalsPlugin};
+pub use typescript_paths_externals_plugin::typescript_paths_externals_plugin;
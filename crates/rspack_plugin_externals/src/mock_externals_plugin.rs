@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  ApplyContext, CompilationBeforeExternalModuleInit, CompilerOptions, ExternalModule, Plugin,
+  PluginContext,
+};
+use rspack_error::{error, Result};
+use rspack_hook::{plugin, plugin_hook};
+
+/// Replaces externals with mocks when enabled, e.g. in test mode to run the bundle in a test
+/// harness without the packages that are loaded at runtime.
+///
+/// The mocks are read from a JSON file mapping the request of an external to the source of
+/// the mock, e.g. `{ "react": "module.exports = { useState() {} };" }`.
+#[plugin]
+#[derive(Debug)]
+pub struct MockExternalsPlugin {
+  mocks: HashMap<String, String>,
+  enabled: bool,
+}
+
+impl MockExternalsPlugin {
+  pub fn new(mocks: HashMap<String, String>, enabled: bool) -> Self {
+    Self::new_inner(mocks, enabled)
+  }
+
+  pub fn from_json_file(path: &Path, enabled: bool) -> Result<Self> {
+    let content = std::fs::read_to_string(path)
+      .map_err(|e| error!("Failed to read mock externals from {}: {e}", path.display()))?;
+    let mocks = serde_json::from_str(&content)
+      .map_err(|e| error!("Invalid mock externals in {}: {e}", path.display()))?;
+    Ok(Self::new(mocks, enabled))
+  }
+
+  fn get_mock(&self, user_request: &str) -> Option<&String> {
+    self.enabled.then(|| self.mocks.get(user_request)).flatten()
+  }
+}
+
+#[plugin_hook(CompilationBeforeExternalModuleInit for MockExternalsPlugin)]
+async fn before_external_module_init(&self, module: &mut ExternalModule) -> Result<()> {
+  if let Some(mock) = self.get_mock(&module.user_request) {
+    module.with_mock_replacement = Some(RawSource::from(mock.clone()).boxed());
+  }
+  Ok(())
+}
+
+impl Plugin for MockExternalsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.MockExternalsPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .before_external_module_init
+      .tap(before_external_module_init::new(self));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_mock_only_when_enabled() {
+    let mocks = HashMap::from([("react".to_string(), "module.exports = {};".to_string())]);
+    let plugin = MockExternalsPlugin::new(mocks.clone(), true);
+    assert_eq!(
+      plugin.get_mock("react").map(String::as_str),
+      Some("module.exports = {};")
+    );
+    assert_eq!(plugin.get_mock("react-dom"), None);
+    let plugin = MockExternalsPlugin::new(mocks, false);
+    assert_eq!(plugin.get_mock("react"), None);
+  }
+}
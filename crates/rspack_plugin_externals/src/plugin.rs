@@ -1,51 +1,139 @@
+use std::collections::BTreeSet;
 use std::fmt::Debug;
-use std::sync::LazyLock;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex};
 
 use regex::Regex;
+use rspack_collections::Identifiable;
 use rspack_core::{
-  ApplyContext, BoxModule, CompilerOptions, ContextInfo, DependencyMeta, ExternalItem,
-  ExternalItemFnCtx, ExternalItemValue, ExternalModule, ExternalRequest, ExternalRequestValue,
-  ExternalType, ExternalTypeEnum, ModuleDependency, ModuleExt, ModuleFactoryCreateData,
-  NormalModuleFactoryFactorize, Plugin, PluginContext,
+  canonical_external_type, parse_resource, ApplyContext, BoxModule, Compilation, CompilationParams,
+  CompilerOptions, CompilerThisCompilation, ContextInfo, DependencyMeta, DependencyType,
+  ExternalItem, ExternalItemFnCtx, ExternalItemResolveData, ExternalItemValue, ExternalModule,
+  ExternalRequest, ExternalRequestValue, ExternalType, ExternalTypeEnum, ModuleExt,
+  ModuleFactoryCreateData, NormalModuleFactoryFactorize, Plugin, PluginContext, ResourceParsedData,
+  RuntimeSpec,
 };
-use rspack_error::Result;
+use rspack_error::{Diagnosable, Diagnostic, Result};
+use rspack_fs::ReadableFileSystem;
 use rspack_hook::{plugin, plugin_hook};
 use rspack_plugin_javascript::dependency::{ESMImportSideEffectDependency, ImportDependency};
+use rustc_hash::FxHashSet as HashSet;
 
 static UNSPECIFIED_EXTERNAL_TYPE_REGEXP: LazyLock<Regex> =
   LazyLock::new(|| Regex::new(r"^[a-z0-9-]+ ").expect("Invalid regex"));
 
+/// Rewrites the request of every external module created by the plugin, e.g. to point
+/// `react@18` to a versioned CDN url.
+pub struct ExternalRequestTransformer(Box<dyn Fn(&str) -> String + Send + Sync>);
+
+impl ExternalRequestTransformer {
+  pub fn new(f: impl Fn(&str) -> String + Send + Sync + 'static) -> Self {
+    Self(Box::new(f))
+  }
+}
+
+impl Debug for ExternalRequestTransformer {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_tuple("ExternalRequestTransformer").finish()
+  }
+}
+
+/// The input file system of the current compilation, the `package.json` of CDN externals is read
+/// through it.
+#[derive(Clone)]
+struct InputFileSystem(Arc<dyn ReadableFileSystem>);
+
+impl Debug for InputFileSystem {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_tuple("InputFileSystem").finish()
+  }
+}
+
 #[plugin]
 #[derive(Debug)]
 pub struct ExternalsPlugin {
   externals: Vec<ExternalItem>,
   r#type: ExternalType,
+  request_transformer: Option<ExternalRequestTransformer>,
+  input_filesystem: Mutex<Option<InputFileSystem>>,
 }
 
 impl ExternalsPlugin {
   pub fn new(r#type: ExternalType, externals: Vec<ExternalItem>) -> Self {
-    Self::new_inner(externals, r#type)
+    Self::new_inner(externals, r#type, None, Default::default())
+  }
+
+  pub fn new_with_request_transformer(
+    r#type: ExternalType,
+    externals: Vec<ExternalItem>,
+    request_transformer: ExternalRequestTransformer,
+  ) -> Self {
+    Self::new_inner(
+      externals,
+      r#type,
+      Some(request_transformer),
+      Default::default(),
+    )
+  }
+
+  fn init_input_filesystem(&self, compilation: &Compilation) {
+    let mut lock = self.input_filesystem.lock().expect("should lock");
+    *lock = Some(InputFileSystem(compilation.input_filesystem.clone()));
+  }
+
+  fn get_input_filesystem(&self) -> Arc<dyn ReadableFileSystem> {
+    let lock = self.input_filesystem.lock().expect("should lock");
+    lock.clone().expect("init_input_filesystem first").0
   }
 
   fn handle_external(
     &self,
     config: &ExternalItemValue,
     r#type: Option<String>,
-    dependency: &dyn ModuleDependency,
+    data: &mut ModuleFactoryCreateData,
+    matched_by: &str,
   ) -> Option<ExternalModule> {
+    let dependency = data.dependencies[0]
+      .as_module_dependency()
+      .expect("should be module dependency");
+    let options = &data.options;
+    let externals_experiments = &options.experiments.externals;
     let (external_module_config, external_module_type) = match config {
       ExternalItemValue::String(config) => {
         let (external_type, config) =
           if let Some((external_type, new_config)) = parse_external_type_from_str(config) {
             (external_type, new_config)
           } else {
-            (self.r#type.clone(), config.to_owned())
+            (
+              externals_experiments
+                .type_inference
+                .then(|| infer_external_type_from_request(config))
+                .flatten()
+                .unwrap_or_else(|| self.r#type.clone()),
+              config.to_owned(),
+            )
           };
         (
           ExternalRequest::Single(ExternalRequestValue::new(config, None)),
           external_type,
         )
       }
+      // `["commonjs lodash", "window _"]`, every item carries its own type, is a fallback
+      // chain rather than a request with a property path.
+      ExternalItemValue::Array(arr)
+        if arr.len() > 1
+          && arr
+            .iter()
+            .all(|item| parse_external_type_from_str(item).is_some()) =>
+      {
+        let chain = arr
+          .iter()
+          .filter_map(|item| parse_external_type_from_str(item))
+          .map(|(external_type, request)| (external_type, ExternalRequestValue::new(request, None)))
+          .collect::<Vec<_>>();
+        let external_type = chain[0].0.clone();
+        (ExternalRequest::Chain(chain), external_type)
+      }
       ExternalItemValue::Array(arr) => {
         let mut iter = arr.iter().peekable();
         let primary = iter.next()?;
@@ -53,7 +141,14 @@ impl ExternalsPlugin {
           if let Some((external_type, new_primary)) = parse_external_type_from_str(primary) {
             (external_type, new_primary)
           } else {
-            (self.r#type.clone(), primary.to_owned())
+            (
+              externals_experiments
+                .type_inference
+                .then(|| infer_external_type_from_request(primary))
+                .flatten()
+                .unwrap_or_else(|| self.r#type.clone()),
+              primary.to_owned(),
+            )
           };
         let rest = iter.peek().is_some().then(|| iter.cloned().collect());
         (
@@ -93,15 +188,6 @@ impl ExternalsPlugin {
       ),
     };
 
-    fn parse_external_type_from_str(v: &str) -> Option<(ExternalType, String)> {
-      if UNSPECIFIED_EXTERNAL_TYPE_REGEXP.is_match(v)
-        && let Some((t, c)) = v.split_once(' ')
-      {
-        return Some((t.to_owned(), c.to_owned()));
-      }
-      None
-    }
-
     let dependency_meta: DependencyMeta = DependencyMeta {
       external_type: {
         if dependency
@@ -116,21 +202,201 @@ impl ExternalsPlugin {
           .is_some()
         {
           Some(ExternalTypeEnum::Module)
+        } else if *dependency.dependency_type() == DependencyType::WasmImport {
+          Some(ExternalTypeEnum::Wasm)
         } else {
           None
         }
       },
     };
 
-    Some(ExternalModule::new(
+    let external_type = r#type.unwrap_or(external_module_type);
+    // `ExternalModule::new` coerces the shorthands wherever the type comes from, the prefix of
+    // the request, `externalsType`, the result of a function external or an item of a chain
+    let chain_types = match &external_module_config {
+      ExternalRequest::Chain(chain) => chain
+        .iter()
+        .map(|(external_type, _)| external_type)
+        .collect(),
+      _ => vec![],
+    };
+    let shorthands = std::iter::once(&external_type)
+      .chain(chain_types)
+      .filter(|external_type| canonical_external_type(external_type).is_some())
+      .cloned()
+      .collect::<BTreeSet<_>>();
+    // ES module output leaves bare specifiers to the import map of the page
+    let external_type = if external_type == "bare-specifier" && options.output.module {
+      "module".to_string()
+    } else {
+      external_type
+    };
+    // CDN shortcuts import the package from the url they expand the request to
+    let (external_type, cdn) = if matches!(external_type.as_str(), "unpkg" | "esm-sh") {
+      ("import".to_string(), Some(external_type))
+    } else {
+      (external_type, None)
+    };
+    let mut external_module = ExternalModule::new(
       external_module_config,
-      r#type.unwrap_or(external_module_type),
+      external_type,
       dependency.request().to_owned(),
       dependency_meta,
-    ))
+    );
+    // Externals loading a package load the package `resolve.alias` maps it to
+    if matches!(
+      external_module.get_external_type().as_str(),
+      "commonjs"
+        | "commonjs2"
+        | "commonjs-module"
+        | "commonjs-static"
+        | "node-commonjs"
+        | "amd"
+        | "umd"
+        | "umd2"
+        | "system"
+        | "import"
+        | "module"
+        | "module-import"
+        | "import-sync"
+    ) {
+      external_module = external_module.with_request_transformer(|request| {
+        ExternalModule::alias_request(request, &options.resolve).into_owned()
+      });
+    }
+    if let Some(cdn) = &cdn {
+      let fs = self.get_input_filesystem();
+      external_module = external_module.with_request_transformer(|request| {
+        let manifest = read_package_manifest(
+          fs.as_ref(),
+          request,
+          options.context.as_ref(),
+          &mut data.file_dependencies,
+          &mut data.missing_dependencies,
+        );
+        ExternalModule::cdn_request(cdn, request, manifest.as_ref())
+          .unwrap_or_else(|| request.to_string())
+      });
+    }
+    let mut external_module = match &self.request_transformer {
+      Some(ExternalRequestTransformer(transform)) => {
+        external_module.with_request_transformer(transform)
+      }
+      None => external_module,
+    };
+    // ES module specifiers are not resolved with `resolve.extensions` at runtime, and the
+    // request of `inline-umd` externals is source code. Only the identifier is normalized, a
+    // package may only export the subpath as configured, e.g. `./fp.js`.
+    if !external_module.get_external_type().starts_with("module")
+      && !external_module.get_external_type().starts_with("import")
+      && external_module.get_external_type() != "inline-umd"
+    {
+      external_module = external_module.with_identifier_transformer(|request| {
+        ExternalModule::normalize_request(request, &options.resolve).into_owned()
+      });
+    }
+    external_module.with_esmodule_interop = options.output.strict_module_error_handling;
+    external_module.csp_safe = externals_experiments.csp_safe;
+    external_module.integrity = externals_experiments
+      .integrity
+      .get(dependency.request())
+      .cloned();
+    external_module.expected_version = externals_experiments
+      .expected_version
+      .get(dependency.request())
+      .cloned();
+    external_module.runtime_condition = externals_experiments
+      .runtime
+      .get(dependency.request())
+      .map(|runtimes| {
+        RuntimeSpec::from_iter(runtimes.iter().map(|runtime| runtime.as_str().into()))
+      });
+    for shorthand in shorthands {
+      let diagnostic = shorthand_external_type_warning(&shorthand, dependency.request());
+      external_module
+        .add_diagnostic(diagnostic.with_module_identifier(Some(external_module.identifier())));
+    }
+    external_module.trace_resolved(self.name(), matched_by);
+    Some(external_module)
   }
 }
 
+/// Reads the `package.json` of the package requested by a package request such as `pkg/sub`
+/// from the `node_modules` directories above `context`.
+///
+/// The manifest found is a file dependency and the candidates before it are missing
+/// dependencies, so the url is updated once the package is installed or upgraded in watch mode.
+fn read_package_manifest(
+  fs: &dyn ReadableFileSystem,
+  request: &str,
+  context: &Path,
+  file_dependencies: &mut HashSet<PathBuf>,
+  missing_dependencies: &mut HashSet<PathBuf>,
+) -> Option<serde_json::Value> {
+  let (package, _) = ExternalModule::split_package_request(request)?;
+  for dir in context.ancestors() {
+    let path = dir.join("node_modules").join(package).join("package.json");
+    match fs.read(&path) {
+      Ok(content) => {
+        file_dependencies.insert(path);
+        return serde_json::from_slice(&content).ok();
+      }
+      Err(_) => {
+        missing_dependencies.insert(path);
+      }
+    }
+  }
+  None
+}
+
+fn parse_external_type_from_str(v: &str) -> Option<(ExternalType, String)> {
+  if UNSPECIFIED_EXTERNAL_TYPE_REGEXP.is_match(v)
+    && let Some((t, c)) = v.split_once(' ')
+  {
+    return Some((t.to_owned(), c.to_owned()));
+  }
+  None
+}
+
+fn shorthand_external_type_warning(shorthand: &str, user_request: &str) -> Diagnostic {
+  let canonical = canonical_external_type(shorthand).unwrap_or(shorthand);
+  Diagnostic::warn(
+    "Deprecated external type".to_string(),
+    format!(
+      "External type \"{shorthand}\" of external \"{user_request}\" is deprecated, use \"{canonical}\" instead."
+    ),
+  )
+}
+
+/// Infers the external type from the file extension of the request.
+///
+/// Enabled by `experiments.externals.typeInference`, a stylesheet url is then loaded as
+/// `css-import` without spelling out the type. An explicit type prefix (`"module ./lib.mjs"`) or
+/// the `externalType` returned from a function external takes precedence.
+pub fn infer_external_type_from_request(request: &str) -> Option<ExternalType> {
+  let path = request.split(['?', '#']).next().unwrap_or(request);
+  let file_name = path.rsplit('/').next().unwrap_or(path);
+  let (_, extension) = file_name.rsplit_once('.')?;
+  let external_type = match extension {
+    "css" => "css-import",
+    "mjs" => "import",
+    "wasm" => "wasm",
+    // a JSON file can be required, imported with attributes or fetched, leave it to `externalsType`
+    _ => return None,
+  };
+  Some(external_type.to_string())
+}
+
+#[plugin_hook(CompilerThisCompilation for ExternalsPlugin)]
+async fn this_compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  self.init_input_filesystem(compilation);
+  Ok(())
+}
+
 #[plugin_hook(NormalModuleFactoryFactorize for ExternalsPlugin)]
 async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<BoxModule>> {
   let dependency = data.dependencies[0]
@@ -143,7 +409,7 @@ async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<B
         let request = dependency.request();
 
         if let Some(value) = eh.get(request) {
-          let maybe_module = self.handle_external(value, None, dependency);
+          let maybe_module = self.handle_external(value, None, data, "object");
           return Ok(maybe_module.map(|i| i.boxed()));
         }
       }
@@ -153,7 +419,8 @@ async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<B
           let maybe_module = self.handle_external(
             &ExternalItemValue::String(request.to_string()),
             None,
-            dependency,
+            data,
+            "regexp",
           );
           return Ok(maybe_module.map(|i| i.boxed()));
         }
@@ -164,27 +431,51 @@ async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<B
           let maybe_module = self.handle_external(
             &ExternalItemValue::String(request.to_string()),
             None,
-            dependency,
+            data,
+            "string",
           );
           return Ok(maybe_module.map(|i| i.boxed()));
         }
       }
       ExternalItem::Fn(f) => {
         let request = dependency.request();
+        let context_info = || ContextInfo {
+          issuer: data
+            .issuer
+            .clone()
+            .map_or("".to_string(), |i| i.to_string()),
+          issuer_layer: data.issuer_layer.clone(),
+        };
+        let (path, query, fragment) = match parse_resource(request) {
+          Some(ResourceParsedData {
+            path,
+            query,
+            fragment,
+          }) => (
+            path.into_string(),
+            query.unwrap_or_default(),
+            fragment.unwrap_or_default(),
+          ),
+          None => (request.to_string(), String::new(), String::new()),
+        };
         let result = f(ExternalItemFnCtx {
           context: context.to_string(),
           request: request.to_string(),
           dependency_type: dependency.category().to_string(),
-          context_info: ContextInfo {
-            issuer: data
-              .issuer
-              .clone()
-              .map_or("".to_string(), |i| i.to_string()),
+          context_info: context_info(),
+          resolve_data: ExternalItemResolveData {
+            context: context.to_string(),
+            request: request.to_string(),
+            dependency_type: dependency.category().to_string(),
+            context_info: context_info(),
+            path,
+            query,
+            fragment,
           },
         })
         .await?;
         if let Some(r) = result.result {
-          let maybe_module = self.handle_external(&r, result.external_type, dependency);
+          let maybe_module = self.handle_external(&r, result.external_type, data, "function");
           return Ok(maybe_module.map(|i| i.boxed()));
         }
       }
@@ -199,6 +490,11 @@ impl Plugin for ExternalsPlugin {
   }
 
   fn apply(&self, ctx: PluginContext<&mut ApplyContext>, _options: &CompilerOptions) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .this_compilation
+      .tap(this_compilation::new(self));
     ctx
       .context
       .normal_module_factory_hooks
@@ -207,3 +503,46 @@ impl Plugin for ExternalsPlugin {
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_infer_external_type_from_request() {
+    assert_eq!(
+      infer_external_type_from_request("https://cdn.example.com/lib.css").as_deref(),
+      Some("css-import")
+    );
+    assert_eq!(
+      infer_external_type_from_request("./style.css?v=1").as_deref(),
+      Some("css-import")
+    );
+    assert_eq!(
+      infer_external_type_from_request("https://cdn.example.com/lib.mjs").as_deref(),
+      Some("import")
+    );
+    assert_eq!(
+      infer_external_type_from_request("./module.wasm#hash").as_deref(),
+      Some("wasm")
+    );
+    // no external type loads JSON by itself, so `externalsType` decides how it is loaded
+    assert_eq!(infer_external_type_from_request("./data.json"), None);
+    assert_eq!(
+      infer_external_type_from_request("https://cdn.example.com/data.json?v=1"),
+      None
+    );
+    assert_eq!(infer_external_type_from_request("lodash"), None);
+    assert_eq!(infer_external_type_from_request("lodash.debounce"), None);
+    assert_eq!(infer_external_type_from_request("./dir.css/index"), None);
+  }
+
+  #[test]
+  fn test_shorthand_external_type_warning() {
+    let diagnostic = shorthand_external_type_warning("cjs2", "lodash");
+    assert_eq!(diagnostic.severity(), rspack_error::RspackSeverity::Warn);
+    assert!(diagnostic
+      .message()
+      .contains("\"cjs2\" of external \"lodash\" is deprecated, use \"commonjs2\" instead"));
+  }
+}
@@ -0,0 +1,163 @@
+use std::path::Path;
+use std::sync::{Arc, LazyLock};
+
+use regex::Regex;
+use rspack_core::{
+  BoxPlugin, ExternalItem, ExternalItemFnCtx, ExternalItemFnResult, ExternalItemValue, PluginExt,
+};
+use rspack_error::{error, Result};
+
+use crate::ExternalsPlugin;
+
+static URL_TARGET: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"^(//|https?://)").expect("Invalid regex"));
+
+/// Externalizes the `compilerOptions.paths` entries of a `tsconfig.json` whose first target is a
+/// URL as `import` externals, e.g. `"$api": ["https://api.example.com/bundle.js"]`, or
+/// `"$api/*": ["https://api.example.com/*.js"]` to map `$api/users` to
+/// `https://api.example.com/users.js`. Other entries are left to the resolver.
+///
+/// Like TypeScript, exact patterns are matched before wildcard patterns, and wildcard patterns
+/// with a longer prefix are matched first. `extends` is not followed.
+pub fn typescript_paths_externals_plugin(config_file: &Path) -> Result<BoxPlugin> {
+  let content = std::fs::read_to_string(config_file)
+    .map_err(|e| error!("Failed to read {}: {e}", config_file.display()))?;
+  let paths = parse_url_paths(&content)
+    .map_err(|e| error!("Invalid tsconfig {}: {e}", config_file.display()))?;
+  Ok(
+    ExternalsPlugin::new(
+      "import".to_owned(),
+      vec![url_paths_external_item(Arc::new(paths))],
+    )
+    .boxed(),
+  )
+}
+
+fn url_paths_external_item(paths: Arc<Vec<(String, String)>>) -> ExternalItem {
+  ExternalItem::Fn(Box::new(move |ctx: ExternalItemFnCtx| {
+    let paths = paths.clone();
+    Box::pin(async move {
+      let result = paths
+        .iter()
+        .find_map(|(pattern, target)| match_path(pattern, target, &ctx.request))
+        .map(ExternalItemValue::String);
+      Ok(ExternalItemFnResult {
+        external_type: None,
+        result,
+      })
+    })
+  }))
+}
+
+/// Reads the `paths` entries mapping to a URL, sorted in the order TypeScript matches them.
+fn parse_url_paths(content: &str) -> std::result::Result<Vec<(String, String)>, String> {
+  let value = jsonc_parser::parse_to_serde_value(
+    content.trim_start_matches('\u{feff}'),
+    &jsonc_parser::ParseOptions {
+      allow_comments: true,
+      allow_trailing_commas: true,
+      allow_loose_object_property_names: false,
+    },
+  )
+  .map_err(|e| e.to_string())?;
+  let Some(paths) = value
+    .as_ref()
+    .and_then(|value| value.pointer("/compilerOptions/paths"))
+  else {
+    return Ok(vec![]);
+  };
+  let paths = paths
+    .as_object()
+    .ok_or_else(|| "compilerOptions.paths should be an object".to_string())?;
+  let mut url_paths = paths
+    .iter()
+    .filter_map(|(pattern, targets)| {
+      let target = targets.as_array()?.first()?.as_str()?;
+      URL_TARGET
+        .is_match(target)
+        .then(|| (pattern.clone(), target.to_string()))
+    })
+    .collect::<Vec<_>>();
+  url_paths.sort_by_key(|(pattern, _)| match pattern.split_once('*') {
+    None => (false, 0),
+    Some((prefix, _)) => (true, usize::MAX - prefix.len()),
+  });
+  Ok(url_paths)
+}
+
+fn match_path(pattern: &str, target: &str, request: &str) -> Option<String> {
+  let Some((prefix, suffix)) = pattern.split_once('*') else {
+    return (pattern == request).then(|| target.to_string());
+  };
+  if request.len() < prefix.len() + suffix.len() {
+    return None;
+  }
+  let matched = request.strip_prefix(prefix)?.strip_suffix(suffix)?;
+  Some(target.replacen('*', matched, 1))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_parse_url_paths() {
+    let paths = parse_url_paths(
+      r#"{
+        // comments are allowed in tsconfig.json
+        "compilerOptions": {
+          "paths": {
+            "$api/*": ["https://api.example.com/*.js"],
+            "$api/v2/*": ["https://api.example.com/v2/*.mjs"],
+            "$api": ["https://api.example.com/bundle.js"],
+            "@/*": ["./src/*"],
+          }
+        }
+      }"#,
+    )
+    .expect("should parse");
+    assert_eq!(
+      paths,
+      vec![
+        (
+          "$api".to_string(),
+          "https://api.example.com/bundle.js".to_string()
+        ),
+        (
+          "$api/v2/*".to_string(),
+          "https://api.example.com/v2/*.mjs".to_string()
+        ),
+        (
+          "$api/*".to_string(),
+          "https://api.example.com/*.js".to_string()
+        ),
+      ]
+    );
+    assert_eq!(parse_url_paths("{}"), Ok(vec![]));
+    assert!(parse_url_paths(r#"{ "compilerOptions": { "paths": [] } }"#).is_err());
+  }
+
+  #[test]
+  fn test_match_path() {
+    assert_eq!(
+      match_path("$api", "https://api.example.com/bundle.js", "$api").as_deref(),
+      Some("https://api.example.com/bundle.js")
+    );
+    assert_eq!(
+      match_path("$api", "https://api.example.com/bundle.js", "$api/users"),
+      None
+    );
+    assert_eq!(
+      match_path("$api/*", "https://api.example.com/*.js", "$api/users").as_deref(),
+      Some("https://api.example.com/users.js")
+    );
+    assert_eq!(
+      match_path("*.remote", "https://cdn.example.com/*.js", "a.remote").as_deref(),
+      Some("https://cdn.example.com/a.js")
+    );
+    assert_eq!(
+      match_path("ab*ba", "https://cdn.example.com/*.js", "aba"),
+      None
+    );
+  }
+}
@@ -2,6 +2,8 @@
 
 mod hot_module_replacement;
 
+use std::collections::BTreeMap;
+
 use async_trait::async_trait;
 use hot_module_replacement::HotModuleReplacementRuntimeModule;
 use rspack_collections::{IdentifierSet, UkeyMap};
@@ -128,6 +130,24 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
     }
   }
 
+  // fingerprints of the updated externals, so the client can invalidate its cached references
+  let updated_externals: BTreeMap<String, String> = {
+    let module_graph = compilation.get_module_graph();
+    updated_modules
+      .iter()
+      .filter_map(|identifier| {
+        let external_module = module_graph
+          .module_by_identifier(identifier)?
+          .as_external_module()?;
+        let module_id = compilation.chunk_graph.get_module_id(*identifier)?;
+        Some((
+          module_id.to_string(),
+          format!("{:016x}", external_module.hot_update_hash()),
+        ))
+      })
+      .collect()
+  };
+
   // TODO: hash
   // if old.hash == now.hash { return  } else { // xxxx}
 
@@ -322,20 +342,18 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         ),
       )
       .always_ok();
+    let mut manifest = serde_json::json!({
+      "c": c,
+      "r": r,
+      "m": m,
+    });
+    if !updated_externals.is_empty() {
+      manifest["externals"] = serde_json::json!(updated_externals);
+    }
     compilation.emit_asset(
       filename,
       CompilationAsset::new(
-        Some(
-          RawSource::from(
-            serde_json::json!({
-              "c": c,
-              "r": r,
-              "m": m,
-            })
-            .to_string(),
-          )
-          .boxed(),
-        ),
+        Some(RawSource::from(manifest.to_string()).boxed()),
         AssetInfo::default().with_hot_module_replacement(Some(true)),
       ),
     );
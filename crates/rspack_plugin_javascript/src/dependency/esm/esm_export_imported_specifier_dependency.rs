@@ -466,6 +466,58 @@ impl ESMExportImportedSpecifierDependency {
     None
   }
 
+  /// `export * from "external"` in the entry module of a `module` library is emitted as is
+  /// by the `ModuleLibraryPlugin` when the external is a `module` external, so the exports of
+  /// the external don't need to be copied to the exports object of the entry module, unless
+  /// other modules import the entry module and read them from its exports object.
+  fn is_star_reexported_by_module_library(
+    &self,
+    compilation: &Compilation,
+    module: &ModuleIdentifier,
+  ) -> bool {
+    if !compilation.options.output.module {
+      return false;
+    }
+    let module_graph = compilation.get_module_graph();
+    let is_module_external = module_graph
+      .get_module_by_dependency_id(&self.id)
+      .and_then(|imported_module| imported_module.as_external_module())
+      .is_some_and(|external_module| {
+        matches!(
+          external_module.get_external_type().as_str(),
+          "module" | "module-import"
+        )
+      });
+    if !is_module_external {
+      return false;
+    }
+    // only the entry dependency, which has no origin module, may import the entry module
+    if module_graph
+      .get_incoming_connections(module)
+      .iter()
+      .any(|connection| connection.original_module_identifier.is_some())
+    {
+      return false;
+    }
+    let chunks = compilation.chunk_graph.get_module_chunks(*module);
+    !chunks.is_empty()
+      && chunks.iter().all(|chunk_ukey| {
+        if !compilation
+          .chunk_graph
+          .get_chunk_entry_modules(chunk_ukey)
+          .contains(module)
+        {
+          return false;
+        }
+        let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+        chunk
+          .get_entry_options(&compilation.chunk_group_by_ukey)
+          .and_then(|options| options.library.as_ref())
+          .or(compilation.options.output.library.as_ref())
+          .is_some_and(|library| library.library_type == "module")
+      })
+  }
+
   fn add_export_fragments(&self, ctxt: &mut TemplateContext, mut mode: ExportMode) {
     let TemplateContext {
       module,
@@ -653,6 +705,8 @@ impl ESMExportImportedSpecifierDependency {
           }
         }
       }
+      ExportModeType::DynamicReexport
+        if self.is_star_reexported_by_module_library(compilation, &module_identifier) => {}
       ExportModeType::DynamicReexport => {
         let ignored = match (mode.hidden.take(), mode.ignored.take()) {
           (None, None) => HashSet::default(),
@@ -1,6 +1,7 @@
 use rspack_core::{
-  AsDependency, Compilation, DependencyId, DependencyTemplate, ExternalModuleInitFragment,
-  InitFragmentExt, InitFragmentStage, RuntimeSpec, TemplateContext, TemplateReplaceSource,
+  AsDependency, ChunkInitFragmentsExt, Compilation, DependencyId, DependencyTemplate,
+  ExternalModuleInitFragment, InitFragmentExt, InitFragmentStage, RuntimeSpec, TemplateContext,
+  TemplateReplaceSource,
 };
 use rspack_util::ext::DynHash;
 
@@ -41,7 +42,7 @@ impl DependencyTemplate for ExternalModuleDependency {
       InitFragmentStage::StageConstants,
       0,
     );
-    chunk_init_fragments.push(fragment.boxed());
+    chunk_init_fragments.add(fragment.boxed());
   }
 
   fn dependency_id(&self) -> Option<DependencyId> {
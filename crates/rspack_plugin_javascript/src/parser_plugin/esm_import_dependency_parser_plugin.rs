@@ -135,6 +135,16 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       .expect_get_tag_info(parser.current_tag_info?);
     let settings = ESMSpecifierData::downcast(tag_info.data.clone()?);
     let range: RealDependencyLocation = ident.span.into();
+    // import * as a from 'a';
+    // const { value } = a;
+    let referenced_properties_in_destructuring = parser
+      .properties_in_destructuring
+      .remove(&ident.sym)
+      .or_else(|| {
+        parser
+          .destructuring_assignment_properties_for(&ident.span)
+          .map(|properties| properties.into_iter().map(Atom::from).collect())
+      });
     let dep = ESMImportSpecifierDependency::new(
       settings.source,
       settings.name,
@@ -146,7 +156,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
       parser.in_tagged_template_tag,
       true,
       ESMImportSpecifierDependency::create_export_presence_mode(parser.javascript_options),
-      parser.properties_in_destructuring.remove(&ident.sym),
+      referenced_properties_in_destructuring,
       settings.attributes,
     );
     let dep_id = *dep.id();
@@ -301,7 +311,7 @@ impl JavascriptParserPlugin for ESMImportDependencyParserPlugin {
 
   // collect referenced properties in destructuring
   // import * as a from 'a';
-  // const { value } = a;
+  // ({ value } = a);
   fn assign(&self, parser: &mut JavascriptParser, assign_expr: &AssignExpr) -> Option<bool> {
     if let AssignTarget::Pat(AssignTargetPat::Object(object_pat)) = &assign_expr.left
       && assign_expr.op == AssignOp::Assign
@@ -32,9 +32,9 @@ use rspack_core::rspack_sources::{
 };
 use rspack_core::{
   basic_function, render_init_fragments, ChunkGraph, ChunkGroupUkey, ChunkInitFragments,
-  ChunkRenderContext, ChunkUkey, CodeGenerationDataTopLevelDeclarations, Compilation,
-  CompilationId, ConcatenatedModuleIdent, ExportsArgument, Module, RuntimeGlobals, SourceType,
-  SpanExt,
+  ChunkInitFragmentsExt, ChunkRenderContext, ChunkUkey, CodeGenerationDataTopLevelDeclarations,
+  Compilation, CompilationId, ConcatenatedModuleIdent, ExportsArgument, Module, RuntimeGlobals,
+  SourceType, SpanExt,
 };
 use rspack_core::{BoxModule, IdentCollector};
 use rspack_error::Result;
@@ -622,7 +622,7 @@ impl JsPlugin {
     {
       let chunk_modules_source =
         if let Some((chunk_modules_source, fragments)) = chunk_modules_result {
-          chunk_init_fragments.extend(fragments);
+          chunk_init_fragments.add_all(fragments);
           chunk_modules_source
         } else {
           RawSource::from("{}").boxed()
@@ -690,8 +690,8 @@ impl JsPlugin {
           };
         }
 
-        chunk_init_fragments.extend(fragments);
-        chunk_init_fragments.extend(additional_fragments);
+        chunk_init_fragments.add_all(fragments);
+        chunk_init_fragments.add_all(additional_fragments);
         let inner_strict = !all_strict && m.build_info().expect("should have build_info").strict;
         let module_runtime_requirements =
           ChunkGraph::get_module_runtime_requirements(compilation, *m_identifier, &chunk.runtime);
@@ -1131,7 +1131,7 @@ impl JsPlugin {
     }
     let (chunk_modules_source, chunk_init_fragments) =
       render_chunk_modules(compilation, chunk_ukey, &chunk_modules, all_strict)?
-        .unwrap_or_else(|| (RawSource::from("{}").boxed(), Vec::new()));
+        .unwrap_or_else(|| (RawSource::from("{}").boxed(), ChunkInitFragments::default()));
     let mut render_source = RenderSource {
       source: chunk_modules_source,
     };
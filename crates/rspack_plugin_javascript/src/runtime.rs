@@ -1,8 +1,8 @@
 use rayon::prelude::*;
 use rspack_core::rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt};
 use rspack_core::{
-  to_normal_comment, BoxModule, ChunkGraph, ChunkInitFragments, ChunkUkey, Compilation,
-  RuntimeGlobals, SourceType,
+  to_normal_comment, BoxModule, ChunkGraph, ChunkInitFragments, ChunkInitFragmentsExt, ChunkUkey,
+  Compilation, RuntimeGlobals, SourceType,
 };
 use rspack_error::{error, Result};
 use rspack_util::diff_mode::is_diff_mode;
@@ -34,8 +34,8 @@ pub fn render_chunk_modules(
   let chunk_init_fragments = module_code_array.iter().fold(
     ChunkInitFragments::default(),
     |mut chunk_init_fragments, (_, _, fragments, additional_fragments)| {
-      chunk_init_fragments.extend((*fragments).clone());
-      chunk_init_fragments.extend(additional_fragments.clone());
+      chunk_init_fragments.add_all((*fragments).clone());
+      chunk_init_fragments.add_all(additional_fragments.clone());
       chunk_init_fragments
     },
   );
@@ -94,6 +94,13 @@ impl DependencyTemplate for ModernModuleImportDependency {
     let request_and_external_type = match &self.target_request {
       ExternalRequest::Single(request) => (Some(request), &self.external_type),
       ExternalRequest::Map(map) => (map.get(&self.external_type), &self.external_type),
+      ExternalRequest::Chain(chain) => (
+        chain
+          .iter()
+          .find(|(external_type, _)| external_type == &self.external_type)
+          .map(|(_, request)| request),
+        &self.external_type,
+      ),
     };
 
     if let Some(request_and_external_type) = request_and_external_type.0 {
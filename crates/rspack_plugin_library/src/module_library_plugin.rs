@@ -3,15 +3,17 @@ use std::hash::Hash;
 use rspack_core::rspack_sources::{ConcatSource, RawSource, SourceExt};
 use rspack_core::{
   property_access, to_identifier, ApplyContext, ChunkUkey, Compilation, CompilationParams,
-  CompilerCompilation, CompilerOptions, LibraryOptions, ModuleGraph, ModuleIdentifier, Plugin,
-  PluginContext,
+  CompilerCompilation, CompilerOptions, ExternalRequest, LibraryOptions, ModuleGraph,
+  ModuleIdentifier, Plugin, PluginContext,
 };
 use rspack_error::{error_bail, Result};
 use rspack_hash::RspackHash;
 use rspack_hook::{plugin, plugin_hook};
 use rspack_plugin_javascript::{
-  JavascriptModulesChunkHash, JavascriptModulesRenderStartup, JsPlugin, RenderSource,
+  dependency::ESMExportImportedSpecifierDependency, JavascriptModulesChunkHash,
+  JavascriptModulesRenderStartup, JsPlugin, RenderSource,
 };
+use rspack_util::json_stringify;
 
 use crate::utils::{get_options_for_chunk, COMMON_LIBRARY_NAME_MESSAGE};
 
@@ -94,10 +96,57 @@ fn render_startup(
       exports.join(", ")
     )));
   }
+  for request in get_star_reexported_externals(&module_graph, module) {
+    source.add(RawSource::from(format!("export * from {request};\n")));
+  }
   render_source.source = source.boxed();
   Ok(())
 }
 
+/// Exports of `export * from "external"` are unknown at build time, so `module` externals
+/// re-exported this way are re-exported from the external directly.
+fn get_star_reexported_externals(
+  module_graph: &ModuleGraph,
+  module: &ModuleIdentifier,
+) -> Vec<String> {
+  let mut requests = module_graph
+    .get_outgoing_connections(module)
+    .into_iter()
+    .filter(|connection| {
+      module_graph
+        .dependency_by_id(&connection.dependency_id)
+        .and_then(|dependency| {
+          dependency
+            .as_any()
+            .downcast_ref::<ESMExportImportedSpecifierDependency>()
+        })
+        .is_some_and(|dependency| dependency.name.is_none() && dependency.ids.is_empty())
+    })
+    .filter_map(|connection| {
+      module_graph
+        .module_by_identifier(connection.module_identifier())?
+        .as_external_module()
+    })
+    .filter_map(|external_module| {
+      let external_type = external_module.get_external_type();
+      if external_type != "module" && external_type != "module-import" {
+        return None;
+      }
+      match &external_module.request {
+        ExternalRequest::Single(request) => Some(request.primary()),
+        ExternalRequest::Map(map) => map.get("module").map(|request| request.primary()),
+        ExternalRequest::Chain(chain) => chain.iter().find_map(|(external_type, request)| {
+          (external_type == "module").then(|| request.primary())
+        }),
+      }
+    })
+    .map(json_stringify)
+    .collect::<Vec<_>>();
+  requests.sort_unstable();
+  requests.dedup();
+  requests
+}
+
 #[plugin_hook(JavascriptModulesChunkHash for ModuleLibraryPlugin)]
 async fn js_chunk_hash(
   &self,
@@ -113,6 +113,9 @@ fn render(
     .map(|m| match &m.request {
       ExternalRequest::Single(request) => Some(request.primary()),
       ExternalRequest::Map(map) => map.get("amd").map(|request| request.primary()),
+      ExternalRequest::Chain(chain) => chain
+        .iter()
+        .find_map(|(external_type, request)| (external_type == "amd").then(|| request.primary())),
     })
     .collect::<Vec<_>>();
   let external_deps_array =
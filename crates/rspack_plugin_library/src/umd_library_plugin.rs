@@ -319,9 +319,21 @@ fn externals_require_array(typ: &str, externals: &[&ExternalModule]) -> Result<S
       .map(|m| {
         let request = match &m.request {
           ExternalRequest::Single(r) => r,
-          ExternalRequest::Map(map) => map
-            .get(typ)
-            .ok_or_else(|| error!("Missing external configuration for type: {typ}"))?,
+          ExternalRequest::Map(map) => map.get(typ).ok_or_else(|| {
+            error!(
+              "Missing external configuration for type: {typ} in {}",
+              m.describe()
+            )
+          })?,
+          ExternalRequest::Chain(chain) => chain
+            .iter()
+            .find_map(|(external_type, r)| (external_type == typ).then_some(r))
+            .ok_or_else(|| {
+              error!(
+                "Missing external configuration for type: {typ} in {}",
+                m.describe()
+              )
+            })?,
         };
         // TODO: check if external module is optional
         let primary =
@@ -346,10 +358,21 @@ fn externals_root_array(modules: &[&ExternalModule]) -> Result<String> {
         let typ = "root";
         let request = match &m.request {
           ExternalRequest::Single(r) => r.iter(),
-          ExternalRequest::Map(map) => map
-            .get(typ)
-            .map(|r| r.iter())
-            .ok_or_else(|| error!("Missing external configuration for type: {typ}"))?,
+          ExternalRequest::Map(map) => map.get(typ).map(|r| r.iter()).ok_or_else(|| {
+            error!(
+              "Missing external configuration for type: {typ} in {}",
+              m.describe()
+            )
+          })?,
+          ExternalRequest::Chain(chain) => chain
+            .iter()
+            .find_map(|(external_type, r)| (external_type == typ).then(|| r.iter()))
+            .ok_or_else(|| {
+              error!(
+                "Missing external configuration for type: {typ} in {}",
+                m.describe()
+              )
+            })?,
         };
         Ok(format!("root{}", accessor_to_object_access(request)))
       })
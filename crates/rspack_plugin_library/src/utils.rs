@@ -1,7 +1,4 @@
-use rspack_collections::Identifiable;
-use rspack_core::{
-  to_identifier, ChunkUkey, Compilation, ExternalModule, ExternalRequest, LibraryOptions,
-};
+use rspack_core::{ChunkUkey, Compilation, ExternalModule, ExternalRequest, LibraryOptions};
 use rspack_error::{error, Result};
 
 pub fn externals_dep_array(modules: &[&ExternalModule]) -> Result<String> {
@@ -11,6 +8,9 @@ pub fn externals_dep_array(modules: &[&ExternalModule]) -> Result<String> {
       Ok(match &m.request {
         ExternalRequest::Single(s) => Some(s.primary()),
         ExternalRequest::Map(map) => map.get("amd").map(|r| r.primary()),
+        ExternalRequest::Chain(chain) => chain
+          .iter()
+          .find_map(|(external_type, r)| (external_type == "amd").then(|| r.primary())),
       })
     })
     .collect::<Result<Vec<_>>>()?;
@@ -20,18 +20,7 @@ pub fn externals_dep_array(modules: &[&ExternalModule]) -> Result<String> {
 fn inner_external_arguments(modules: &[&ExternalModule], compilation: &Compilation) -> Vec<String> {
   modules
     .iter()
-    .map(|m| {
-      format!(
-        "__WEBPACK_EXTERNAL_MODULE_{}__",
-        to_identifier(
-          compilation
-            .get_module_graph()
-            .module_graph_module_by_identifier(&m.identifier())
-            .expect("Module not found")
-            .id(&compilation.chunk_graph)
-        )
-      )
-    })
+    .map(|m| m.get_external_variable_name(compilation))
     .collect::<Vec<_>>()
 }
 
@@ -6,7 +6,7 @@ use std::{cmp, sync::atomic::AtomicU32, time::Instant};
 
 use async_trait::async_trait;
 use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
-use rspack_collections::IdentifierMap;
+use rspack_collections::{IdentifierMap, IdentifierSet};
 use rspack_core::{
   ApplyContext, BoxModule, Compilation, CompilationAfterOptimizeModules,
   CompilationAfterProcessAssets, CompilationBuildModule, CompilationChunkIds,
@@ -70,6 +70,8 @@ pub struct ProgressPlugin {
   pub modules_count: AtomicU32,
   pub modules_done: AtomicU32,
   pub active_modules: RwLock<IdentifierMap<Instant>>,
+  // external modules, tagged with "external" in the building reports
+  pub external_modules: RwLock<IdentifierSet>,
   pub last_modules_count: RwLock<Option<u32>>,
   pub last_active_module: RwLock<Option<ModuleIdentifier>>,
   pub last_state_info: RwLock<Vec<ProgressPluginStateInfo>>,
@@ -111,6 +113,7 @@ impl ProgressPlugin {
       Default::default(),
       Default::default(),
       Default::default(),
+      Default::default(),
       AtomicU32::new(0),
     )
   }
@@ -142,6 +145,14 @@ impl ProgressPlugin {
 
     if let Some(last_active_module) = *last_active_module {
       items.push(last_active_module.to_string());
+      if self
+        .external_modules
+        .read()
+        .expect("TODO:")
+        .contains(&last_active_module)
+      {
+        items.push("external".to_string());
+      }
       let duration = self
         .active_modules
         .read()
@@ -316,6 +327,7 @@ async fn make(&self, _compilation: &mut Compilation) -> Result<()> {
   self.handler(0.1, String::from("make"), vec![], None)?;
   self.modules_count.store(0, Relaxed);
   self.modules_done.store(0, Relaxed);
+  self.external_modules.write().expect("TODO:").clear();
   Ok(())
 }
 
@@ -327,6 +339,13 @@ async fn build_module(&self, module: &mut BoxModule) -> Result<()> {
     .expect("TODO:")
     .insert(module.identifier(), Instant::now());
   self.modules_count.fetch_add(1, Relaxed);
+  if module.as_external_module().is_some() {
+    self
+      .external_modules
+      .write()
+      .expect("TODO:")
+      .insert(module.identifier());
+  }
   self
     .last_active_module
     .write()
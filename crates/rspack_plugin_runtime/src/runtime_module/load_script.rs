@@ -1,4 +1,5 @@
 use cow_utils::CowUtils;
+use itertools::Itertools;
 use rspack_collections::Identifier;
 use rspack_core::{
   impl_runtime_module,
@@ -77,6 +78,39 @@ impl RuntimeModule for LoadScriptRuntimeModule {
       ))
     };
 
+    let script_integrities = compilation
+      .get_module_graph()
+      .modules()
+      .values()
+      .filter_map(|module| module.as_external_module())
+      .filter_map(|module| module.get_script_integrity())
+      .map(|(url, integrity)| (url.to_string(), integrity.to_string()))
+      .sorted()
+      .collect::<Vec<_>>();
+    let (integrity_map, integrity_set_attribute) = if script_integrities.is_empty() {
+      (String::new(), "")
+    } else {
+      (
+        format!(
+          "var scriptIntegrity = {{{}}};",
+          script_integrities
+            .iter()
+            .map(|(url, integrity)| format!(
+              "{}: {}",
+              serde_json::to_string(url).expect("invalid json to_string"),
+              serde_json::to_string(integrity).expect("invalid json to_string")
+            ))
+            .join(", ")
+        ),
+        r#"
+        if (scriptIntegrity[url]) {
+          script.integrity = scriptIntegrity[url];
+          if (!script.crossOrigin) script.crossOrigin = "anonymous";
+        }
+        "#,
+      )
+    };
+
     let script_charset = if compilation.options.output.charset {
       "script.charset = 'utf-8';".to_string()
     } else {
@@ -122,6 +156,8 @@ impl RuntimeModule for LoadScriptRuntimeModule {
             None => "",
           },
         )
+        .cow_replace("$INTEGRITY_MAP$", &integrity_map)
+        .cow_replace("$INTEGRITY_SET_ATTRIBUTE$", integrity_set_attribute)
         .cow_replace(
           "$UNIQUE_PREFIX$",
           unique_prefix.unwrap_or_default().as_str(),
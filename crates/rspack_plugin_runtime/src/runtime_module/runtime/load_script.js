@@ -1,6 +1,7 @@
 var inProgress = {};
 
 $UNIQUE_PREFIX$
+$INTEGRITY_MAP$
 // loadScript function to load a script via script tag
 __webpack_require__.l = function (url, done, key, chunkId$FETCH_PRIORITY$) {
 	if (inProgress[url]) {
@@ -32,6 +33,7 @@ __webpack_require__.l = function (url, done, key, chunkId$FETCH_PRIORITY$) {
 		script.src = $URL$;
 
 		__CROSS_ORIGIN_LOADING_PLACEHOLDER__
+		$INTEGRITY_SET_ATTRIBUTE$
 	}
 	inProgress[url] = [done];
 	var onScriptComplete = function (prev, event) {
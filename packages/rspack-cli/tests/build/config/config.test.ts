@@ -152,6 +152,25 @@ describe("rspack cli", () => {
 		});
 	});
 
+	describe("should load esm config with externals functions", () => {
+		const cwd = resolve(__dirname, "./esm-externals");
+
+		it("should support async and callback externals in config.mjs", async () => {
+			const { exitCode, stderr, stdout } = await run(cwd, [
+				"-c",
+				"rspack.config.mjs"
+			]);
+			expect(stderr).toBeFalsy();
+			expect(stdout).toBeTruthy();
+			expect(exitCode).toBe(0);
+			const bundle = await readFile(
+				resolve(cwd, "./dist/externals.bundle.js"),
+				{ encoding: "utf-8" }
+			);
+			expect(bundle).toMatch(/module.exports = 'from async function';/);
+			expect(bundle).toMatch(/module.exports = 'undefined';/);
+		});
+	});
 	describe("should load config with defineConfig helper", () => {
 		const cwd = resolve(__dirname, "./esm");
 
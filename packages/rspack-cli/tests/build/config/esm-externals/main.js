@@ -0,0 +1,4 @@
+import asyncExternal from "async-external";
+import callbackExternal from "callback-external";
+
+console.log(asyncExternal, callbackExternal);
@@ -0,0 +1,28 @@
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+
+const __dirname = path.dirname(fileURLToPath(import.meta.url));
+
+export default {
+	mode: "development",
+	devtool: false,
+	entry: path.resolve(__dirname, "main.js"),
+	output: {
+		path: path.resolve(__dirname, "dist"),
+		filename: "externals.bundle.js"
+	},
+	externals: [
+		async ({ request }) => {
+			if (request === "async-external") {
+				return "var 'from async function'";
+			}
+			return undefined;
+		},
+		function ({ request }, callback) {
+			if (request === "callback-external") {
+				return callback(null, `var '${typeof this}'`);
+			}
+			callback();
+		}
+	]
+};
@@ -0,0 +1,51 @@
+const { Compilation } = require("@rspack/core");
+
+let source;
+
+class CaptureMinimizedPlugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap("CaptureMinimizedPlugin", compilation => {
+			compilation.hooks.processAssets.tap(
+				{
+					name: "CaptureMinimizedPlugin",
+					stage: Compilation.PROCESS_ASSETS_STAGE_REPORT
+				},
+				assets => {
+					source = assets["main.mjs"].source().toString();
+				}
+			);
+		});
+	}
+}
+
+/** @type {import('../..').TCompilerCaseConfig} */
+module.exports = {
+	description: "should keep the stub of module externals intact when minimizing",
+	options(context) {
+		return {
+			context: context.getSource(),
+			mode: "production",
+			entry: "./module-external",
+			externals: ["path"],
+			externalsType: "module",
+			output: {
+				module: true,
+				chunkFormat: "module",
+				filename: "[name].mjs"
+			},
+			experiments: {
+				outputModule: true
+			},
+			optimization: {
+				minimize: true,
+				concatenateModules: false
+			},
+			plugins: [new CaptureMinimizedPlugin()]
+		};
+	},
+	async check() {
+		const namespace = /import\s*\*\s*as\s*([\w$]+)\s*from\s*"path"/.exec(source);
+		expect(namespace).toBeTruthy();
+		expect(source.split(namespace[1]).length).toBeGreaterThan(2);
+	}
+};
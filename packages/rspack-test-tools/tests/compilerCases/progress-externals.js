@@ -0,0 +1,31 @@
+const { ProgressPlugin } = require("@rspack/core");
+
+const reports = [];
+
+/** @type {import('../..').TCompilerCaseConfig} */
+module.exports = {
+	description: "should report progress up to 100% when the build only has externals",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./externals-only",
+			externals: {
+				"external-a": "commonjs external-a",
+				"external-b": "commonjs external-b"
+			},
+			plugins: [
+				new ProgressPlugin((percentage, message, ...items) => {
+					reports.push({ percentage, message, items });
+				})
+			]
+		};
+	},
+	async check() {
+		expect(reports.at(-1).percentage).toBe(1);
+		for (const { message, items } of reports) {
+			if (message === "building" && items[0]?.startsWith("external ")) {
+				expect(items[1]).toBe("external");
+			}
+		}
+	}
+};
@@ -0,0 +1,5 @@
+it("should expose the value of an external", async () => {
+	const exposed = await import("A/path");
+	expect(exposed.join).toBe(require("path").join);
+	expect(exposed.default).toBe(require("path"));
+});
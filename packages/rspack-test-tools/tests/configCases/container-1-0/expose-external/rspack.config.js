@@ -0,0 +1,28 @@
+const { ModuleFederationPluginV1: ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js"
+	},
+	target: "async-node",
+	externals: {
+		"external-path": "commonjs path"
+	},
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "A",
+			filename: "container-a.js",
+			library: {
+				type: "commonjs-module"
+			},
+			exposes: {
+				"./path": "external-path"
+			},
+			remoteType: "commonjs-module",
+			remotes: {
+				A: "./container-a.js"
+			}
+		})
+	]
+};
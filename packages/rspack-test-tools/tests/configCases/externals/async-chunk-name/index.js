@@ -0,0 +1,12 @@
+import fs from "fs";
+import path from "path";
+
+it("should name the async chunk of externals after webpackChunkName", async function () {
+	const { default: eol } = await import(
+		/* webpackChunkName: "vendors" */ "./vendors"
+	);
+	expect(typeof eol).toBe("string");
+
+	const js = fs.readFileSync(path.resolve(__dirname, "vendors.js"), "utf-8");
+	expect(js).toContain('module.exports = require("os");');
+});
@@ -0,0 +1,3 @@
+import { EOL } from "os";
+
+export default EOL;
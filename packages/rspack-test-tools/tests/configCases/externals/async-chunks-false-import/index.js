@@ -0,0 +1,14 @@
+import fs from "node:fs";
+import url from "node:url";
+import external from "external-path";
+
+it("should import externals statically when async chunks are disabled", function () {
+	expect(typeof external.join).toBe("function");
+
+	const source = fs.readFileSync(url.fileURLToPath(import.meta.url), "utf-8");
+	// split the requests so that these assertions do not match themselves
+	expect(source).toContain(
+		"import * as __WEBPACK_EXTERNAL_MODULE_node_path__ from " + '"node:path";'
+	);
+	expect(source).not.toContain("import(" + '"node:path")');
+});
@@ -0,0 +1,16 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		module: true,
+		chunkFormat: "module",
+		filename: "bundle0.mjs",
+		asyncChunks: false
+	},
+	externals: {
+		"external-path": "import node:path"
+	},
+	experiments: {
+		outputModule: true
+	},
+	target: "node"
+};
@@ -0,0 +1,5 @@
+module.exports = [
+	[
+		/external 'promise' module 'external-lib' is loaded asynchronously, which is not supported when output.asyncChunks is false/
+	]
+];
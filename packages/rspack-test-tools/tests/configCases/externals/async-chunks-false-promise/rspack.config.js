@@ -0,0 +1,9 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		asyncChunks: false
+	},
+	externals: {
+		"external-lib": "promise Promise.resolve(42)"
+	}
+};
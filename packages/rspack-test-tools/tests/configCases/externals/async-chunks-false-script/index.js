@@ -0,0 +1,7 @@
+function load() {
+	return import("external-lib");
+}
+
+it("should report script externals when async chunks are disabled", function () {
+	expect(typeof load).toBe("function");
+});
@@ -0,0 +1,9 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		asyncChunks: false
+	},
+	externals: {
+		"external-lib": "script externalLib@https://example.com/external-lib.js"
+	}
+};
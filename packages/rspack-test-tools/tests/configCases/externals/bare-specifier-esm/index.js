@@ -0,0 +1,11 @@
+import fs from "node:fs";
+import url from "node:url";
+import { join } from "external-path";
+
+it("should keep bare specifiers as static imports with ES module output", function () {
+	expect(typeof join).toBe("function");
+
+	const source = fs.readFileSync(url.fileURLToPath(import.meta.url), "utf-8");
+	// split the request so that this assertion does not match itself
+	expect(source).toContain("import * as __WEBPACK_EXTERNAL_MODULE_node_path__ from " + '"node:path";');
+});
@@ -0,0 +1,6 @@
+import lodash from "lodash-es";
+
+it("should replace bare specifiers with an empty namespace without ES module output", function () {
+	expect(Object.keys(lodash)).toEqual([]);
+	expect(lodash[Symbol.toStringTag]).toBe("Module");
+});
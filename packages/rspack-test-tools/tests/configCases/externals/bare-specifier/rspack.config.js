@@ -0,0 +1,6 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: {
+		"lodash-es": "bare-specifier lodash-es"
+	}
+};
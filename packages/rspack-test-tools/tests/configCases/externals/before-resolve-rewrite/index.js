@@ -0,0 +1,5 @@
+import os from "aliased-os";
+
+it("should externalize the request rewritten by beforeResolve", function () {
+	expect(os).toBe(__non_webpack_require__("os"));
+});
@@ -0,0 +1,25 @@
+const PLUGIN_NAME = "RewriteExternalsPlugin";
+
+class RewriteExternalsPlugin {
+	/**
+	 * @param {import("@rspack/core").Compiler} compiler
+	 */
+	apply(compiler) {
+		compiler.hooks.normalModuleFactory.tap(PLUGIN_NAME, normalModuleFactory => {
+			normalModuleFactory.hooks.beforeResolve.tap(PLUGIN_NAME, resolveData => {
+				if (resolveData.request.startsWith("aliased-")) {
+					resolveData.request = resolveData.request.slice("aliased-".length);
+				}
+			});
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	externals: {
+		os: "commonjs os"
+	},
+	plugins: [new RewriteExternalsPlugin()]
+};
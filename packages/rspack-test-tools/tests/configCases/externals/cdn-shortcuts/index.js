@@ -0,0 +1,25 @@
+import fs from "fs";
+import path from "path";
+
+it("should expand CDN shortcuts to versioned urls", function () {
+	// never called, the urls cannot be loaded in the test
+	const load = () =>
+		Promise.all([
+			import("fake-lib"),
+			import("fake-lib/fp"),
+			import("@scope/fake-ui"),
+			import("not-installed")
+		]);
+	expect(typeof load).toBe("function");
+
+	const source = fs
+		.readdirSync(__dirname)
+		.filter(file => file.endsWith(".js"))
+		.map(file => fs.readFileSync(path.join(__dirname, file), "utf-8"))
+		.join("\n");
+	// split the requests so that these assertions do not match themselves
+	expect(source).toContain('import("https://unpkg.com/' + 'fake-lib@4.17.21/dist/fake-lib.js")');
+	expect(source).toContain('import("https://esm.sh/' + 'fake-lib@4.17.21/fp")');
+	expect(source).toContain('import("https://esm.sh/' + '@scope/fake-ui@1.2.3")');
+	expect(source).toContain('import("https://esm.sh/' + 'not-installed")');
+});
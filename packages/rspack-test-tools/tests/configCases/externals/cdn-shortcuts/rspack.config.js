@@ -0,0 +1,10 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	externals: {
+		"fake-lib": "unpkg fake-lib",
+		"fake-lib/fp": "esm-sh fake-lib/fp",
+		"@scope/fake-ui": "esm-sh @scope/fake-ui",
+		"not-installed": "esm-sh not-installed"
+	}
+};
@@ -0,0 +1,29 @@
+import esModule from "es-module-flagged";
+import * as esModuleNs from "es-module-flagged";
+import plain from "plain-commonjs";
+import * as plainNs from "plain-commonjs";
+
+it("should use exports.default when __esModule is set", function () {
+	expect(esModule).toBe("default export");
+	expect(esModuleNs.named).toBe("named export");
+});
+
+it("should use module.exports when __esModule is not set", function () {
+	expect(plain).toEqual({ default: "not the default export", value: 42 });
+});
+
+it("should only expose module.exports as the default export with strictModuleErrorHandling when __esModule is not set", function () {
+	if (INTEROP) {
+		expect(plainNs.value).toBeUndefined();
+	} else {
+		expect(plainNs.value).toBe(42);
+	}
+	expect(plainNs.default).toBe(plain);
+});
+
+it("should require the real module.exports without strictModuleErrorHandling", function () {
+	if (!INTEROP) {
+		expect(require("plain-commonjs")).toBe(plain);
+		expect(require("plain-commonjs").value).toBe(42);
+	}
+});
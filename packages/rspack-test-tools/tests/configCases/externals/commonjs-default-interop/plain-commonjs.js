@@ -0,0 +1,4 @@
+module.exports = {
+	default: "not the default export",
+	value: 42
+};
@@ -0,0 +1,20 @@
+const path = require("path");
+const { DefinePlugin } = require("@rspack/core");
+
+const config = ({ strictModuleErrorHandling }) => ({
+	target: "node",
+	output: {
+		strictModuleErrorHandling
+	},
+	externals: {
+		"es-module-flagged": `commonjs ${path.resolve(__dirname, "es-module-flagged.js")}`,
+		"plain-commonjs": `commonjs ${path.resolve(__dirname, "plain-commonjs.js")}`
+	},
+	plugins: [new DefinePlugin({ INTEROP: strictModuleErrorHandling })]
+});
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	config({ strictModuleErrorHandling: false }),
+	config({ strictModuleErrorHandling: true })
+];
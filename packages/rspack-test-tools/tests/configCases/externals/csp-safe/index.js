@@ -0,0 +1,25 @@
+import fs from "fs";
+
+function loadLibrary() {
+	return import("cdn-lib");
+}
+
+function loadJQuery() {
+	try {
+		return require("jquery");
+	} catch (e) {}
+}
+
+it("should not evaluate strings in the code generated for externals", function () {
+	expect(typeof loadLibrary).toBe("function");
+	expect(typeof loadJQuery).toBe("function");
+
+	const source = fs.readFileSync(__filename, "utf-8");
+	// the expected code is assembled so that it does not match the source of this test
+	expect(source).toContain(["__webpack_require__", "l"].join("."));
+	expect(source).toContain(
+		["Object.prototype.hasOwnProperty.call(window", '"jQuery")'].join(", ")
+	);
+	expect(source).not.toMatch(new RegExp(["new", "Function"].join("\\s+")));
+	expect(source).not.toMatch(new RegExp(["\\beval", "\\("].join("\\s*")));
+});
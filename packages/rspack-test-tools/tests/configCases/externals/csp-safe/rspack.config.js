@@ -0,0 +1,13 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	externals: {
+		"cdn-lib": "script cdnLib@https://cdn.example.com/lib.js",
+		jquery: "window jQuery"
+	},
+	experiments: {
+		externals: {
+			cspSafe: true
+		}
+	}
+};
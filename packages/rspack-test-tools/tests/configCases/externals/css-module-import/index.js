@@ -0,0 +1,24 @@
+import * as styles from "./index.module.css";
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should compose class names from an external css module", () => {
+	expect(styles).toEqual(
+		nsObj({
+			button: "./index.module-button base",
+			link: "./index.module-link base primary",
+			missing: "./index.module-missing undefined-in-ui-kit"
+		})
+	);
+});
+
+it("should export class names the external stylesheet does not define as is", () => {
+	// the external stylesheet is only loaded at runtime, so its class names cannot be checked
+	expect(styles.missing).toBe("./index.module-missing undefined-in-ui-kit");
+});
+
+it("should import the external stylesheet", () => {
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	const importUrl = /@import url\("(.*)"\);/.exec(css)[1];
+	expect(importUrl).toBe("https://cdn.example.com/ui-kit/base.module.css");
+});
@@ -0,0 +1,20 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	node: false,
+	externals: {
+		"ui-kit/base.module.css":
+			"css-module-import https://cdn.example.com/ui-kit/base.module.css"
+	},
+	module: {
+		generator: {
+			"css/auto": {
+				exportsOnly: false,
+				localIdentName: "[path][name]-[local]"
+			}
+		}
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,22 @@
+import "./index.css";
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should define custom properties of the paint worklet with @property", () => {
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	expect(css).toContain(
+		"@property --checkerboard-spacing {\n  syntax: \"<length>\";\n  inherits: false;\n  initial-value: 8px;\n}"
+	);
+	expect(css).toContain(
+		"@property --checkerboard-color {\n  syntax: \"<color>\";\n  inherits: true;\n  initial-value: black;\n}"
+	);
+	expect(css).not.toContain("@import url(\"--checkerboard");
+});
+
+it("should keep @import rules of css-import externals first", () => {
+	const css = fs.readFileSync(path.resolve(__dirname, "bundle0.css"), "utf-8");
+	expect(css.indexOf('@import url("https://cdn.example.com/ui-kit/base.css");')).toBe(
+		css.indexOf("@import")
+	);
+	expect(css.indexOf("@import")).toBeLessThan(css.indexOf("@property"));
+});
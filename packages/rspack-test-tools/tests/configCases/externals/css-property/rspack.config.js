@@ -0,0 +1,22 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	node: false,
+	externals: {
+		"checkerboard/spacing":
+			"css-property --checkerboard-spacing; syntax: '<length>'; inherits: false; initial-value: 8px",
+		"checkerboard/color":
+			"css-property --checkerboard-color; syntax: <color>; inherits: true; initial-value: black",
+		"ui-kit/base.css": "css-import https://cdn.example.com/ui-kit/base.css"
+	},
+	module: {
+		generator: {
+			"css/auto": {
+				exportsOnly: false
+			}
+		}
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,14 @@
+import logo from "./logo.svg";
+import pixel from "./pixel.png";
+
+it("should export the data URI of svg externals", function () {
+	expect(logo).toBe(
+		"data:image/svg+xml,%3Csvg%20xmlns='http://www.w3.org/2000/svg'/%3E"
+	);
+});
+
+it("should export the data URI of png externals", function () {
+	expect(pixel).toBe(
+		"data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII="
+	);
+});
@@ -0,0 +1,20 @@
+const svg = "data:image/svg+xml,%3Csvg%20xmlns='http://www.w3.org/2000/svg'/%3E";
+const png =
+	"data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII=";
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	externals: {
+		"./logo.svg": svg,
+		"./pixel.png": `commonjs ${png}`
+	},
+	plugins: [
+		compiler => {
+			compiler.hooks.done.tap("Test", stats => {
+				const assets = Object.keys(stats.compilation.assets);
+				expect(assets).toEqual(["bundle0.js"]);
+			});
+		}
+	]
+};
@@ -0,0 +1,15 @@
+import fs from "node:fs";
+import url from "node:url";
+import path from "node:path";
+import runtimeRequire from "runtime-require";
+
+it("should export a require function created for the module output", function () {
+	expect(typeof runtimeRequire).toBe("function");
+	const name = ["pa", "th"].join("");
+	expect(runtimeRequire(name).join).toBe(path.join);
+
+	const source = fs.readFileSync(url.fileURLToPath(import.meta.url), "utf-8");
+	const createRequire = "__WEBPACK_EXTERNAL_createRequire";
+	expect(source).toContain(`${createRequire}(import.meta.url);`);
+	expect(source).not.toContain(["module.exports", "__webpack_require__;"].join(" = "));
+});
@@ -0,0 +1,13 @@
+import fs from "fs";
+import path from "path";
+import runtimeRequire from "runtime-require";
+
+it("should export the raw require function", function () {
+	expect(typeof runtimeRequire).toBe("function");
+	const name = ["pa", "th"].join("");
+	expect(runtimeRequire(name).join).toBe(path.join);
+
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toContain(["module.exports", "require;"].join(" = "));
+	expect(source).not.toContain(["module.exports", "__webpack_require__;"].join(" = "));
+});
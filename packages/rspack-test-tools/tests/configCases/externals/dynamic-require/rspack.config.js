@@ -0,0 +1,27 @@
+const externals = {
+	"runtime-require": "dynamic-require runtime-require"
+};
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		target: "node",
+		externals,
+		output: {
+			filename: "bundle0.js"
+		}
+	},
+	{
+		entry: "./esm.js",
+		target: "node",
+		externals,
+		output: {
+			module: true,
+			chunkFormat: "module",
+			filename: "bundle1.mjs"
+		},
+		experiments: {
+			outputModule: true
+		}
+	}
+];
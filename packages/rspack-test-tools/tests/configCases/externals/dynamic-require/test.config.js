@@ -0,0 +1,6 @@
+/** @type {import("../../../..").TConfigCaseConfig} */
+module.exports = {
+	findBundle: i => {
+		return [i === 0 ? "bundle0.js" : "bundle1.mjs"];
+	}
+};
@@ -0,0 +1,3 @@
+module.exports = [
+	[/Entrypoints 'b' and 'a' import each other in a circular way/]
+];
@@ -0,0 +1,5 @@
+import { load } from "b-entry";
+
+it("should report entries importing each other", function () {
+	expect(typeof load).toBe("function");
+});
@@ -0,0 +1,15 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		a: "./index.js",
+		b: "./b.js"
+	},
+	target: "web",
+	output: {
+		filename: "[name].js"
+	},
+	externals: {
+		"a-entry": "entry-import a",
+		"b-entry": "entry-import b"
+	}
+};
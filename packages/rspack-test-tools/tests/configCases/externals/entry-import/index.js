@@ -0,0 +1,6 @@
+import shared from "shared-entry";
+
+it("should load the imported entry through __webpack_require__", function () {
+	expect(shared.value).toBe(42);
+	expect(shared.loaded).toBe(1);
+});
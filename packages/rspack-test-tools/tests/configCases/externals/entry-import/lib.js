@@ -0,0 +1,3 @@
+global.__entryImportLoaded = (global.__entryImportLoaded || 0) + 1;
+
+module.exports = { value: 42, loaded: global.__entryImportLoaded };
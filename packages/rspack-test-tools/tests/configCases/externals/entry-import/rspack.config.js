@@ -0,0 +1,14 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		lib: "./lib.js"
+	},
+	target: "web",
+	output: {
+		filename: "[name].js"
+	},
+	externals: {
+		"shared-entry": "entry-import lib"
+	}
+};
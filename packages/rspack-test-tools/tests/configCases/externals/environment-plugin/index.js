@@ -0,0 +1,7 @@
+import apiUrl from "api-url";
+import apiRetries from "api-retries";
+
+it("should replace process.env in var externals with the EnvironmentPlugin values", function () {
+	expect(apiUrl).toBe("https://api.example.com");
+	expect(apiRetries).toBe("3");
+});
@@ -0,0 +1,15 @@
+const { EnvironmentPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: {
+		"api-url": "var process.env.EXTERNALS_API_URL",
+		"api-retries": "var process.env.EXTERNALS_API_RETRIES"
+	},
+	plugins: [
+		new EnvironmentPlugin({
+			EXTERNALS_API_URL: "https://api.example.com",
+			EXTERNALS_API_RETRIES: "3"
+		})
+	]
+};
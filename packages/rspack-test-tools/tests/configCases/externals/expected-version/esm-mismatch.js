@@ -0,0 +1,3 @@
+import lib from "esm-mismatch";
+
+export const version = lib.version;
@@ -0,0 +1,42 @@
+let warn;
+
+beforeEach(() => {
+	warn = jest.spyOn(console, "warn").mockImplementation(() => {});
+});
+
+afterEach(() => {
+	warn.mockRestore();
+});
+
+it("should not warn when an external satisfies its expected version", function () {
+	expect(require("sync-match").version).toBe("18.2.0");
+	expect(warn).not.toHaveBeenCalled();
+});
+
+it("should warn when an external does not satisfy its expected version", function () {
+	expect(require("sync-mismatch").version).toBe("17.0.2");
+	expect(warn).toHaveBeenCalledWith(
+		"Version 17.0.2 of external module sync-mismatch does not satisfy the expected version ^18.0.0"
+	);
+});
+
+it("should check async externals once they are loaded", async function () {
+	globalThis.asyncMatch = Promise.resolve({ version: "2.1.3" });
+	globalThis.asyncMismatch = Promise.resolve({ version: "2.2.0" });
+
+	await import("async-match");
+	expect(warn).not.toHaveBeenCalled();
+
+	await import("async-mismatch");
+	expect(warn).toHaveBeenCalledWith(
+		"Version 2.2.0 of external module async-mismatch does not satisfy the expected version ~2.1.0"
+	);
+});
+
+it("should check externals imported by concatenated modules", async function () {
+	const { version } = await import("./esm-mismatch");
+	expect(version).toBe("17.0.2");
+	expect(warn).toHaveBeenCalledWith(
+		"Version 17.0.2 of external module esm-mismatch does not satisfy the expected version ^18.0.0"
+	);
+});
@@ -0,0 +1 @@
+module.exports = { version: "18.2.0" };
@@ -0,0 +1 @@
+module.exports = { version: "17.0.2" };
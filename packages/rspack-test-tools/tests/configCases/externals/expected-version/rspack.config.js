@@ -0,0 +1,27 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	externals: {
+		"sync-match": `commonjs ${path.resolve(__dirname, "matching.js")}`,
+		"sync-mismatch": `commonjs ${path.resolve(__dirname, "mismatching.js")}`,
+		"esm-mismatch": `commonjs ${path.resolve(__dirname, "mismatching.js")}`,
+		"async-match": "promise asyncMatch",
+		"async-mismatch": "promise asyncMismatch"
+	},
+	experiments: {
+		externals: {
+			expectedVersion: {
+				"sync-match": "^18.0.0",
+				"sync-mismatch": "^18.0.0",
+				"esm-mismatch": "^18.0.0",
+				"async-match": "~2.1.0",
+				"async-mismatch": "~2.1.0"
+			}
+		}
+	},
+	optimization: {
+		concatenateModules: true
+	}
+};
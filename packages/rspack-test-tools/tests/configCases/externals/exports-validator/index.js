@@ -0,0 +1,4 @@
+it("should warn about unknown named imports of externals", function () {
+	// the warning is asserted in warnings.js, `validate.js` is never executed
+	expect(typeof __webpack_require__).toBe("function");
+});
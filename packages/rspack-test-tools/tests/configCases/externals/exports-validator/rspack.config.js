@@ -0,0 +1,17 @@
+const { ExternalExportsValidatorRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		validate: "./validate.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	externalsType: "commonjs",
+	externals: {
+		"fake-react": "fake-react"
+	},
+	plugins: [new ExternalExportsValidatorRspackPlugin()]
+};
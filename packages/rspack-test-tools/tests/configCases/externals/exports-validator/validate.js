@@ -0,0 +1,3 @@
+import { useState, useHook, version } from "fake-react";
+
+export { useState, useHook, version };
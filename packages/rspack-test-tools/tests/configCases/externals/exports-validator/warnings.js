@@ -0,0 +1 @@
+module.exports = [[/export 'useHook' was not found in external 'fake-react'/]];
@@ -0,0 +1,7 @@
+import path from "path";
+import $ from "jquery";
+
+it("should load the externals", function () {
+	expect(typeof path.join).toBe("function");
+	expect($).toBe("jquery");
+});
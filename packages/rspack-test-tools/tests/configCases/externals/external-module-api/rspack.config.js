@@ -0,0 +1,59 @@
+const { ExternalModule } = require("@rspack/core");
+
+const PLUGIN_NAME = "plugin";
+
+class Plugin {
+	/**
+	 * @param {import("@rspack/core").Compiler} compiler
+	 */
+	apply(compiler) {
+		compiler.hooks.compilation.tap(PLUGIN_NAME, compilation => {
+			const succeeded = {};
+			compilation.hooks.succeedModule.tap(PLUGIN_NAME, module => {
+				if (module instanceof ExternalModule) {
+					succeeded[module.userRequest] = [
+						module.externalType,
+						module.externalRequest
+					];
+				}
+			});
+			compilation.hooks.finishModules.tap(PLUGIN_NAME, () => {
+				const pathModule = compilation.getExternalModule("path");
+				expect(pathModule).toBeInstanceOf(ExternalModule);
+				expect(pathModule.externalType).toBe("node-commonjs");
+				expect(pathModule.externalRequest).toBe("path");
+				expect(pathModule.userRequest).toBe("path");
+
+				const jqueryModule = compilation.getExternalModule("jquery", "var");
+				expect(jqueryModule).toBeInstanceOf(ExternalModule);
+				expect(jqueryModule.externalRequest).toBe("'jquery'");
+
+				expect(compilation.getExternalModule("jquery", "commonjs")).toBe(
+					undefined
+				);
+				expect(compilation.getExternalModule("lodash")).toBe(undefined);
+
+				const externalModules = Array.from(compilation.modules).filter(
+					module => module instanceof ExternalModule
+				);
+				expect(externalModules.map(module => module.userRequest).sort()).toEqual([
+					"jquery",
+					"path"
+				]);
+				expect(succeeded).toEqual({
+					jquery: ["var", "'jquery'"],
+					path: ["node-commonjs", "path"]
+				});
+			});
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	externals: {
+		jquery: "var 'jquery'"
+	},
+	plugins: [new Plugin()]
+};
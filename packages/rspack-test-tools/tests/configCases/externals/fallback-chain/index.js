@@ -0,0 +1,18 @@
+import fs from "fs";
+import nodePath from "path";
+import twoLevel from "two-level";
+import threeLevel from "three-level";
+
+it("should fall back to the first available external", function () {
+	expect(twoLevel).toBe(nodePath);
+	expect(threeLevel).toBe(process);
+
+	const js = fs.readFileSync(__filename, "utf-8");
+	expect(js).toContain(
+		'typeof __missing_fallback_global__ !== "undefined" ? __missing_fallback_global__ : ' +
+			'require("path")'
+	);
+	expect(js).toContain(
+		'typeof global !== "undefined" ? global.process : ' + 'require("process")'
+	);
+});
@@ -0,0 +1,11 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: {
+		"two-level": ["var __missing_fallback_global__", "commonjs path"],
+		"three-level": [
+			"var __missing_fallback_global__",
+			"global process",
+			"commonjs process"
+		]
+	}
+};
@@ -0,0 +1,3 @@
+module.exports = [
+	[/requires __webpack_require__\.S, but the runtime of entry chunk main does not include the share scope runtime/]
+];
@@ -0,0 +1,3 @@
+it("should report the missing share scope runtime", function () {
+	return import("delegated-shared").catch(() => {});
+});
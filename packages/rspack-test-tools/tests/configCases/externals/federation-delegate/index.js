@@ -0,0 +1,11 @@
+import delegated from "delegated-shared";
+import missing from "delegated-missing";
+
+it("should read eager shared modules from the share scope", function () {
+	expect(delegated.name).toBe("shared");
+	expect(__webpack_require__.S.default.shared["1.0.0"].loaded).toBe(1);
+});
+
+it("should be undefined when the share scope has no eager version", function () {
+	expect(missing).toBe(undefined);
+});
@@ -0,0 +1,21 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: {
+		"delegated-shared": "federation-delegate shared",
+		"delegated-missing": "federation-delegate legacy:shared"
+	},
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "host",
+			shared: {
+				shared: {
+					import: "./shared",
+					eager: true,
+					version: "1.0.0"
+				}
+			}
+		})
+	]
+};
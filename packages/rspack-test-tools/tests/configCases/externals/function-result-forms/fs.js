@@ -0,0 +1 @@
+module.exports = "fake fs";
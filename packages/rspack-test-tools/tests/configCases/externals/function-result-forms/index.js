@@ -0,0 +1,10 @@
+import path from "path";
+import fs from "fs";
+
+it("should externalize with callback(null, result)", function () {
+	expect(typeof path.join).toBe("function");
+});
+
+it("should bundle when the async function resolves to undefined", function () {
+	expect(fs).toBe("fake fs");
+});
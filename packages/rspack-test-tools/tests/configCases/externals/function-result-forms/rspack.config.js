@@ -0,0 +1,25 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	resolve: {
+		alias: {
+			fs$: require("path").resolve(__dirname, "fs.js")
+		}
+	},
+	externalsPresets: {
+		node: false
+	},
+	externals: [
+		({ request }, callback) => {
+			if (request === "path") {
+				return callback(null, "commonjs path");
+			}
+			callback();
+		},
+		async ({ request }) => {
+			if (request === "fs") {
+				return undefined;
+			}
+		}
+	]
+};
@@ -0,0 +1,3 @@
+import { join } from "./middle";
+
+export default join("a", "b");
@@ -0,0 +1,13 @@
+import fs from "fs";
+import path from "path";
+
+it("should make the importers of import externals async", async function () {
+	const { default: joined } = await import(
+		/* webpackChunkName: "consumer" */ "./consumer"
+	);
+	expect(joined).toBe(path.join("a", "b"));
+
+	const source = fs.readFileSync(path.resolve(__dirname, "consumer.js"), "utf-8");
+	// the importer of the external and its own importer are async modules
+	expect(source.match(/__webpack_require__\.a\(/g)).toHaveLength(2);
+});
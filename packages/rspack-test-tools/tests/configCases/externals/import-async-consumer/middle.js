@@ -0,0 +1,3 @@
+import path from "external-path";
+
+export const join = path.join;
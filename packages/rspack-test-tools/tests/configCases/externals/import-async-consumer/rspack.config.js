@@ -0,0 +1,10 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	output: {
+		chunkFilename: "[name].js"
+	},
+	externals: {
+		"external-path": "import node:path"
+	}
+};
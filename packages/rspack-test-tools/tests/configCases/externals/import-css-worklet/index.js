@@ -0,0 +1,12 @@
+import fs from "fs";
+import checkerboard from "checkerboard";
+
+it("should register css paint worklet externals", function () {
+	expect(checkerboard).toEqual({});
+
+	const js = fs.readFileSync(__filename, "utf-8");
+	expect(js).toContain(
+		"CSS.paintWorklet.addModule(" +
+			'"https://cdn.example.com/checkerboard.worklet.js");'
+	);
+});
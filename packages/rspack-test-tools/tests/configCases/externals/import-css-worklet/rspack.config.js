@@ -0,0 +1,6 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: {
+		checkerboard: "import-css-worklet https://cdn.example.com/checkerboard.worklet.js"
+	}
+};
@@ -0,0 +1,12 @@
+import fs from "fs";
+import path from "path";
+import loadLibrary from "lazy-library";
+
+it("should export a factory for import-lazy externals", function () {
+	expect(typeof loadLibrary).toBe("function");
+
+	const js = fs.readFileSync(path.resolve(__dirname, "bundle0.js"), "utf-8");
+	expect(js).toContain(
+		'module.exports = function() { return import("https://cdn.example.com/library.mjs"); };'
+	);
+});
@@ -0,0 +1,6 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: {
+		"lazy-library": "import-lazy https://cdn.example.com/library.mjs"
+	}
+};
@@ -0,0 +1,13 @@
+import fs from "node:fs";
+import url from "node:url";
+import path from "path-utils";
+import util from "node-utils/util";
+
+it("should externalize the specifiers mapped in the import map", function () {
+	expect(typeof path.join).toBe("function");
+	expect(typeof util.format).toBe("function");
+
+	const source = fs.readFileSync(url.fileURLToPath(import.meta.url), "utf-8");
+	expect(source).toContain('from "node:path"');
+	expect(source).toContain('from "node:util"');
+});
@@ -0,0 +1,15 @@
+const { ImportMapExternalsPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		module: true,
+		chunkFormat: "module",
+		filename: "bundle0.mjs"
+	},
+	experiments: {
+		outputModule: true
+	},
+	target: "node",
+	plugins: [new ImportMapExternalsPlugin({ path: "./importmap.json" })]
+};
@@ -0,0 +1,12 @@
+import fs from "node:fs";
+import url from "node:url";
+import captured from "external-process";
+
+it("should resolve import-script externals to the global", function () {
+	expect(captured).toBe(process);
+
+	const source = fs.readFileSync(url.fileURLToPath(import.meta.url), "utf-8");
+	expect(source).toContain(
+		'import("node:process").then(function() { return ' + "process; });"
+	);
+});
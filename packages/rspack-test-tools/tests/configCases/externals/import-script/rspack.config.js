@@ -0,0 +1,15 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		module: true,
+		chunkFormat: "module",
+		filename: "bundle0.mjs"
+	},
+	externals: {
+		"external-process": "import-script process@node:process"
+	},
+	experiments: {
+		outputModule: true
+	},
+	target: "node"
+};
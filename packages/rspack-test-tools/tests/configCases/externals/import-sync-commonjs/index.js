@@ -0,0 +1,7 @@
+import path from "path";
+
+it("should fall back to require for import-sync externals in commonjs output", function () {
+	expect(typeof path.join).toBe("function");
+	const source = require("fs").readFileSync(__filename, "utf-8");
+	expect(source).toContain(`module.exports = require("path");`);
+});
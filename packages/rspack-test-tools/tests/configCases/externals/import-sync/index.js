@@ -0,0 +1,18 @@
+import fs from "node:fs";
+import url from "node:url";
+import path from "node:path";
+import deepmerge from "deepmerge";
+
+it("should load import-sync externals of packages via createRequire", function () {
+	expect(deepmerge({ a: 1 }, { b: 2 })).toEqual({ a: 1, b: 2 });
+	expect(typeof path.join).toBe("function");
+
+	const __filename = url.fileURLToPath(import.meta.url);
+	const source = fs.readFileSync(
+		path.join(__filename, "../../../../js/config/externals/import-sync/main.mjs"),
+		"utf-8"
+	);
+	const createRequire = "__WEBPACK_EXTERNAL_createRequire";
+	expect(source).toContain(`${createRequire}(import.meta.url)("deepmerge")`);
+	expect(source).not.toContain("import.meta.resolve(");
+});
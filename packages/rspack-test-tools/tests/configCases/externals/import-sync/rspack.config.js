@@ -0,0 +1,16 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	externals: {
+		"node:path": "import-sync node:path",
+		deepmerge: "import-sync deepmerge"
+	},
+	output: {
+		module: true,
+		chunkFormat: "module",
+		filename: "[name].mjs"
+	},
+	experiments: {
+		outputModule: true
+	}
+};
@@ -0,0 +1,18 @@
+import fs from "node:fs";
+import url from "node:url";
+import path from "node:path";
+
+it("should await import externals at the top level of async chunks", async function () {
+	const { default: external } = await import(
+		/* webpackChunkName: "async" */ "./async"
+	);
+	expect(typeof external.join).toBe("function");
+
+	const source = fs.readFileSync(
+		path.join(path.dirname(url.fileURLToPath(import.meta.url)), "async.mjs"),
+		"utf-8"
+	);
+	expect(source).toContain(
+		'const __WEBPACK_EXTERNAL_IMPORT_node_path__ = await import("node:path");'
+	);
+});
@@ -0,0 +1,17 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		module: true,
+		chunkFormat: "module",
+		filename: "bundle0.mjs",
+		chunkFilename: "[name].mjs"
+	},
+	externals: {
+		"external-path": "import node:path"
+	},
+	experiments: {
+		outputModule: true,
+		topLevelAwait: true
+	},
+	target: "node"
+};
@@ -0,0 +1,7 @@
+import legacy from "legacy";
+
+it("should evaluate inline UMD sources through their CommonJS branch", function () {
+	expect(legacy.name).toBe("legacy");
+	expect(legacy.add(1, 2)).toBe(3);
+	expect(typeof globalThis.legacy).toBe("undefined");
+});
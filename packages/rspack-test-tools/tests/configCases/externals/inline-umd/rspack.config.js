@@ -0,0 +1,18 @@
+const legacyUmd = `(function (root, factory) {
+	if (typeof define === "function" && define.amd) {
+		define([], factory);
+	} else if (typeof module === "object" && module.exports) {
+		module.exports = factory();
+	} else {
+		root.legacy = factory();
+	}
+})(this, function () {
+	return { name: "legacy", add: function (a, b) { return a + b; } };
+});`;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: {
+		legacy: `inline-umd ${legacyUmd}`
+	}
+};
@@ -0,0 +1,3 @@
+module.exports = [
+	[/External request "lib\\nalert\(1\)" must not contain line breaks/]
+];
@@ -0,0 +1,7 @@
+function load() {
+	return require("external-lib");
+}
+
+it("should report invalid external requests", function () {
+	expect(typeof load).toBe("function");
+});
@@ -0,0 +1,6 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: {
+		"external-lib": "var lib\nalert(1)"
+	}
+};
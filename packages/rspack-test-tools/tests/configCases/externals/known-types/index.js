@@ -0,0 +1,11 @@
+import math from "math";
+import json from "json";
+import path from "path";
+import url from "url";
+
+it("should load externals of every declaration and shorthand type", function () {
+	expect(math.max(1, 2)).toBe(2);
+	expect(json.stringify({ a: 1 })).toBe('{"a":1}');
+	expect(typeof path.join).toBe("function");
+	expect(typeof url.pathToFileURL).toBe("function");
+});
@@ -0,0 +1,10 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	externals: {
+		math: "const Math",
+		json: "let JSON",
+		path: "cjs path",
+		url: "cjs2 url"
+	}
+};
@@ -0,0 +1,4 @@
+module.exports = [
+	[/External type "cjs" of external "path"/],
+	[/External type "cjs2" of external "url"/]
+];
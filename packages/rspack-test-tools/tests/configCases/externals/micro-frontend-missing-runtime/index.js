@@ -0,0 +1,3 @@
+it("should report the missing federation runtime", function () {
+	return import("mf://app/Button").catch(() => {});
+});
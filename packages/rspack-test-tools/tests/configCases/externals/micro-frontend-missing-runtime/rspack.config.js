@@ -0,0 +1,6 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: {
+		"mf://app/Button": "micro-frontend mf://app/Button"
+	}
+};
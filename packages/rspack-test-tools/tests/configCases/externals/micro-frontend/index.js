@@ -0,0 +1,5 @@
+import button from "mf://app/Button";
+
+it("should load the remote module through the federation runtime", function () {
+	expect(button).toEqual({ id: "app/Button" });
+});
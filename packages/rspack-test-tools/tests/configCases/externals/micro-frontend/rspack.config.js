@@ -0,0 +1,13 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: ["./mock-federation.js", "./index.js"],
+	externalsType: "micro-frontend",
+	externals: [/^mf:\/\//],
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "host"
+		})
+	]
+};
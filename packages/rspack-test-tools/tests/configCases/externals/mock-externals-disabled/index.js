@@ -0,0 +1,6 @@
+import path from "path";
+import api from "fake-api";
+
+it("should load the external when not in test mode", function () {
+	expect(api.join).toBe(path.join);
+});
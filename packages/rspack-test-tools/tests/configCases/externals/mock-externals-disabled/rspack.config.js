@@ -0,0 +1,16 @@
+const path = require("path");
+const { MockExternalsRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	externals: {
+		"fake-api": "commonjs path"
+	},
+	plugins: [
+		new MockExternalsRspackPlugin({
+			path: path.resolve(__dirname, "../mock-externals/mocks.json"),
+			enabled: false
+		})
+	]
+};
@@ -0,0 +1,5 @@
+import api from "fake-api";
+
+it("should use the mock of the external in test mode", function () {
+	expect(api).toEqual({ mocked: true });
+});
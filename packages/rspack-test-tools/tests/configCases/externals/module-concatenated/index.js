@@ -0,0 +1,17 @@
+import fs from "node:fs";
+import url from "node:url";
+import path from "node:path";
+import { joined } from "./lib";
+
+it("should keep the import of module externals in concatenated modules", function () {
+	expect(joined).toBe(path.join("a", "b"));
+	const __filename = url.fileURLToPath(import.meta.url);
+	const source = fs.readFileSync(
+		path.join(
+			__filename,
+			"../../../../js/config/externals/module-concatenated/bundle0.mjs"
+		),
+		"utf-8"
+	);
+	expect(source).toMatch(/import \* as [\w$]+ from "node:path";/);
+});
@@ -0,0 +1,3 @@
+import { join } from "external-path";
+
+export const joined = join("a", "b");
@@ -0,0 +1,21 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	output: {
+		module: true,
+		iife: false,
+		chunkFormat: "module",
+		filename: "bundle0.mjs"
+	},
+	externals: {
+		"external-path": "module node:path"
+	},
+	optimization: {
+		concatenateModules: true,
+		minimize: false
+	},
+	experiments: {
+		outputModule: true
+	},
+	target: "node"
+};
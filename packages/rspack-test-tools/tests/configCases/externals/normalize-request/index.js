@@ -0,0 +1,18 @@
+import fs from "fs";
+
+// the externals are only loaded at runtime when called
+function load() {
+	return [require("fp-js"), require("fp-plain"), require("debounce-js")];
+}
+
+it("should treat differently written requests as the same external", function () {
+	expect(typeof load).toBe("function");
+	const source = fs.readFileSync(__filename, "utf-8");
+	const fp = "require(" + JSON.stringify("lodash/fp").slice(0, -1);
+	expect(source.split(fp)).toHaveLength(2);
+});
+
+it("should load the request as configured", function () {
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toContain("require(" + JSON.stringify("lodash/debounce.js") + ")");
+});
@@ -0,0 +1,8 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: {
+		"fp-js": "commonjs lodash/fp.js",
+		"fp-plain": "commonjs lodash/fp",
+		"debounce-js": "commonjs lodash/debounce.js"
+	}
+};
@@ -0,0 +1,10 @@
+import fs from "fs";
+import path from "path";
+
+it("should load externals from the host of each chunk", function () {
+	const us = fs.readFileSync(path.resolve(__dirname, "us.js"), "utf-8");
+	const eu = fs.readFileSync(path.resolve(__dirname, "eu.js"), "utf-8");
+	expect(us).toContain('import("https://us.cdn.example.com/lib.mjs")');
+	expect(eu).toContain('import("https://eu.cdn.example.com/lib.mjs")');
+	expect(eu).not.toContain("us.cdn.example.com");
+});
@@ -0,0 +1,20 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		us: "./region.js",
+		eu: "./region.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	externalsType: "import",
+	externals: {
+		lib: "https://us.cdn.example.com/lib.mjs"
+	},
+	perChunkExternalOverrides: {
+		eu: {
+			lib: "https://eu.cdn.example.com/lib.mjs"
+		}
+	}
+};
@@ -0,0 +1,11 @@
+import fs from "fs";
+import path from "path";
+import { value } from "./reexport";
+
+it("should drop var externals that are only re-exported and unused", function () {
+	// `React` is not defined, so evaluating the external would throw
+	expect(value).toBe(42);
+
+	const js = fs.readFileSync(path.resolve(__dirname, "bundle0.js"), "utf-8");
+	expect(js).not.toContain("module.exports = " + "React;");
+});
@@ -0,0 +1,2 @@
+export { default as React } from "react";
+export const value = 42;
@@ -0,0 +1,11 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externalsType: "var",
+	externals: {
+		react: "React"
+	},
+	optimization: {
+		sideEffects: true,
+		usedExports: true
+	}
+};
@@ -0,0 +1,3 @@
+module.exports = [
+	[/does not include the Module Federation runtime/]
+];
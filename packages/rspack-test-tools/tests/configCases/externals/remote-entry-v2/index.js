@@ -0,0 +1,12 @@
+import fs from "fs";
+import app from "app";
+
+it("should read the remote container from the federation v2 runtime", async function () {
+	expect(app.name).toBe("app");
+	const factory = await app.get("./Button");
+	expect(factory()).toEqual({ request: "./Button" });
+
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toContain(".federation.getRemote(" + JSON.stringify("app") + ")");
+	expect(source).not.toContain(".federation.loadRemote(" + JSON.stringify("app"));
+});
@@ -0,0 +1,10 @@
+__webpack_require__.federation.getRemote = function (name) {
+	return {
+		name,
+		get: function (request) {
+			return Promise.resolve(function () {
+				return { request };
+			});
+		}
+	};
+};
@@ -0,0 +1,14 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: ["./mock-federation.js", "./index.js"],
+	externals: {
+		app: "remote-entry-v2 app"
+	},
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "host"
+		})
+	]
+};
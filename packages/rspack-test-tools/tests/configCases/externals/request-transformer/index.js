@@ -0,0 +1,9 @@
+import fs from "fs";
+import path from "my-path";
+
+it("should load the request returned by the request transformer", function () {
+	expect(path.join).toBe(__non_webpack_require__("path").join);
+
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toContain("require(" + JSON.stringify("node:path") + ")");
+});
@@ -0,0 +1,13 @@
+const { ExternalsPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	plugins: [
+		new ExternalsPlugin(
+			"commonjs",
+			{ "my-path": "path" },
+			request => `node:${request}`
+		)
+	]
+};
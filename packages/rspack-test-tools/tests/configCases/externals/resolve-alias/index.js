@@ -0,0 +1,9 @@
+import path from "path";
+import fsPromises from "fs/promises";
+import fakePath from "fake-path";
+import fakeFsPromises from "fake-fs/promises";
+
+it("should load the aliased package of externals", function () {
+	expect(fakePath.join).toBe(path.join);
+	expect(fakeFsPromises.readFile).toBe(fsPromises.readFile);
+});
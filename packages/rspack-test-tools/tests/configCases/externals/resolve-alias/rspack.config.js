@@ -0,0 +1,14 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	resolve: {
+		alias: {
+			"fake-path$": "path",
+			"fake-fs": "fs"
+		}
+	},
+	externals: {
+		"fake-path": "commonjs fake-path",
+		"fake-fs/promises": "commonjs fake-fs/promises"
+	}
+};
@@ -0,0 +1,7 @@
+import a from "./a";
+import b from "./b";
+
+it("should externalize based on the issuer in resolve data", function () {
+	expect(a).toBe("?inline #frag");
+	expect(b).toBe("bundled");
+});
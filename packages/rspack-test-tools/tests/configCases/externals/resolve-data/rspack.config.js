@@ -0,0 +1,17 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: [
+		(data, callback, resolveData) => {
+			if (
+				resolveData.path === "./lib" &&
+				resolveData.contextInfo.issuer.endsWith("a.js")
+			) {
+				return callback(
+					null,
+					`var ${JSON.stringify(`${resolveData.query} ${resolveData.fragment}`)}`
+				);
+			}
+			callback();
+		}
+	]
+};
@@ -0,0 +1,8 @@
+import fs from "fs";
+import { platform } from "./shared";
+
+it("should not load externals in the runtimes they are not needed in", function () {
+	expect(platform()).toBeUndefined();
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).not.toContain(["require(", JSON.stringify("os"), ")"].join(""));
+});
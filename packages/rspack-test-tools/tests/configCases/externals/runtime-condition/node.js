@@ -0,0 +1,8 @@
+import fs from "fs";
+import { platform } from "./shared";
+
+it("should load externals in the runtimes they are needed in", function () {
+	expect(platform()).toBe(__non_webpack_require__("os").platform());
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toContain(["require(", JSON.stringify("os"), ")"].join(""));
+});
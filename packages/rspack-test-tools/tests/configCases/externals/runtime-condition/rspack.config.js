@@ -0,0 +1,24 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	entry: {
+		browser: "./browser.js",
+		node: "./node.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	externals: {
+		"node-os": "commonjs os"
+	},
+	experiments: {
+		externals: {
+			runtime: {
+				"node-os": ["node"]
+			}
+		}
+	},
+	optimization: {
+		concatenateModules: true
+	}
+};
@@ -0,0 +1,5 @@
+import os from "node-os";
+
+export function platform() {
+	return os.platform && os.platform();
+}
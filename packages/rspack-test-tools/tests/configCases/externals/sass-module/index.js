@@ -0,0 +1,7 @@
+import styles from "./button.module.scss";
+
+it("should map class names from the CSS module manifest", function () {
+	expect(styles.container).toBe("container__abc123");
+	expect(styles.title).toBe("title__def456");
+	expect(styles.missing).toBe(undefined);
+});
@@ -0,0 +1,6 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: {
+		"./button.module.scss": "sass-module ./button.module.json"
+	}
+};
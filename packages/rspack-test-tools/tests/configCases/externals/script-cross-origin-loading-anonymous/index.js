@@ -0,0 +1,8 @@
+it("should load script externals with crossOrigin=`anonymous`", function () {
+	// the global is never defined, so loading the external fails
+	require("cdn-lib").catch(() => {});
+	const script = document.head._children[document.head._children.length - 1];
+	expect(script._type).toBe("script");
+	expect(script.src).toBe("https://cdn.example.com/lib.js");
+	expect(script.crossOrigin).toBe("anonymous");
+});
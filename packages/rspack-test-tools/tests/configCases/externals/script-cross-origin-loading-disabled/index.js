@@ -0,0 +1,7 @@
+it("should load script externals without crossOrigin by default", function () {
+	// the global is never defined, so loading the external fails
+	require("cdn-lib").catch(() => {});
+	const script = document.head._children[document.head._children.length - 1];
+	expect(script._type).toBe("script");
+	expect(script.crossOrigin).toBeUndefined();
+});
@@ -0,0 +1,7 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	externals: {
+		"cdn-lib": "script cdnLib@https://cdn.example.com/lib.js"
+	}
+};
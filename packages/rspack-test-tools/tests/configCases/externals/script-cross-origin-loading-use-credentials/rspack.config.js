@@ -0,0 +1,10 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	output: {
+		crossOriginLoading: "use-credentials"
+	},
+	externals: {
+		"cdn-lib": "script cdnLib@https://cdn.example.com/lib.js"
+	}
+};
@@ -0,0 +1,21 @@
+const lastScript = () => document.head._children[document.head._children.length - 1];
+
+it("should set the integrity of script externals from experiments.externals.integrity", function () {
+	// the globals are never defined, so loading the externals fails
+	require("cdn-lib").catch(() => {});
+	const script = lastScript();
+	expect(script._type).toBe("script");
+	expect(script.src).toBe("https://cdn.example.com/lib.js");
+	expect(script.integrity).toBe(
+		"sha384-oqVuAfXRKap7fdgcCY5uykM6+R9GqQ8K/uxy9rx7HNQlGYl1kPzQho1wx4JwY8wC"
+	);
+	expect(script.crossOrigin).toBe("anonymous");
+});
+
+it("should not set the integrity of script externals without a hash", function () {
+	require("other-lib").catch(() => {});
+	const script = lastScript();
+	expect(script.src).toBe("https://cdn.example.com/other.js");
+	expect(script.integrity).toBeUndefined();
+	expect(script.crossOrigin).toBeUndefined();
+});
@@ -0,0 +1,16 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	externals: {
+		"cdn-lib": "script cdnLib@https://cdn.example.com/lib.js",
+		"other-lib": "script otherLib@https://cdn.example.com/other.js"
+	},
+	experiments: {
+		externals: {
+			integrity: {
+				"cdn-lib":
+					"sha384-oqVuAfXRKap7fdgcCY5uykM6+R9GqQ8K/uxy9rx7HNQlGYl1kPzQho1wx4JwY8wC"
+			}
+		}
+	}
+};
@@ -0,0 +1,13 @@
+import fs from "fs";
+import path from "path";
+import util from "util";
+import url from "url";
+import os from "os";
+
+it("should load externals with shorthand types as commonjs", function () {
+	expect(typeof fs.readFileSync).toBe("function");
+	expect(typeof path.join).toBe("function");
+	expect(typeof util.format).toBe("function");
+	expect(typeof url.pathToFileURL).toBe("function");
+	expect(typeof os.platform).toBe("function");
+});
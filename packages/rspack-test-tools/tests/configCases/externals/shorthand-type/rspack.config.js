@@ -0,0 +1,19 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	externalsType: "cjs",
+	externals: [
+		{
+			fs: "commonjs-module fs",
+			path: "cjs2 path",
+			util: "util",
+			url: ["cjs2 url", "global url"]
+		},
+		({ request }, callback) => {
+			if (request === "os") {
+				return callback(null, "os", "cjs");
+			}
+			callback();
+		}
+	]
+};
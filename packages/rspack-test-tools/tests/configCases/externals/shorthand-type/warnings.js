@@ -0,0 +1,6 @@
+module.exports = [
+	[/External type "cjs2" of external "path" is deprecated, use "commonjs2" instead/],
+	[/External type "cjs" of external "util" is deprecated, use "commonjs" instead/],
+	[/External type "cjs2" of external "url" is deprecated, use "commonjs2" instead/],
+	[/External type "cjs" of external "os" is deprecated, use "commonjs" instead/]
+];
@@ -0,0 +1,22 @@
+import fs from "fs";
+
+function loadLibrary() {
+	return import("remote-library");
+}
+
+it("should reference the url of remote externals in the source map", function () {
+	expect(typeof loadLibrary).toBe("function");
+
+	const map = JSON.parse(fs.readFileSync(`${__filename}.map`, "utf-8"));
+	const url = "https://cdn.example.com/library.mjs";
+	if (SOURCE_ROOT) {
+		// the url would not be relative to the source root
+		expect(map.sourceRoot).toBe(SOURCE_ROOT);
+		expect(map.sources).not.toContain(url);
+		expect(map.sources.some(source => source.includes("external"))).toBe(true);
+	} else {
+		expect(map.sources).toContain(url);
+		expect(map.sourcesContent[map.sources.indexOf(url)]).toBeFalsy();
+	}
+	expect(map.sources.some(source => source.endsWith("index.js"))).toBe(true);
+});
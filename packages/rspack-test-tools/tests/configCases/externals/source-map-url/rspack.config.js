@@ -0,0 +1,15 @@
+const { DefinePlugin, SourceMapDevToolPlugin } = require("@rspack/core");
+
+const config = sourceRoot => ({
+	devtool: false,
+	externals: {
+		"remote-library": "import https://cdn.example.com/library.mjs"
+	},
+	plugins: [
+		new SourceMapDevToolPlugin({ filename: "[file].map", sourceRoot }),
+		new DefinePlugin({ SOURCE_ROOT: JSON.stringify(sourceRoot ?? null) })
+	]
+});
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [config(), config("/sources/")];
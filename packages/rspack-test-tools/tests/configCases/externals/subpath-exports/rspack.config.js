@@ -0,0 +1,13 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		validate: "./validate.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	externalsType: "commonjs",
+	externals: ["fake-lib/sub", "fake-lib/internal"],
+	validateSubpathExports: true
+};
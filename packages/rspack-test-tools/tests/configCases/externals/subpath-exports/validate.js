@@ -0,0 +1,4 @@
+import sub from "fake-lib/sub";
+import internal from "fake-lib/internal";
+
+export { sub, internal };
@@ -0,0 +1,5 @@
+module.exports = [
+	[
+		/Subpath "\.\/internal" of external 'commonjs' module 'fake-lib\/internal' is not exported/
+	]
+];
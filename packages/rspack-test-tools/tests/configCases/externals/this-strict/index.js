@@ -0,0 +1,11 @@
+import "./setup";
+import fs from "fs";
+import name from "this-name";
+
+it("should read this externals from the global object", function () {
+	expect(name).toBe("this-strict");
+
+	const source = fs.readFileSync(__filename, "utf-8");
+	const strict = source.includes('"use strict"; return typeof this');
+	expect(strict).toBe(STRICT_THIS);
+});
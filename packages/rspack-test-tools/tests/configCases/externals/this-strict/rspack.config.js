@@ -0,0 +1,16 @@
+const { DefinePlugin } = require("@rspack/core");
+
+const config = (output, strict) => ({
+	output,
+	externals: {
+		"this-name": "this externalName"
+	},
+	plugins: [new DefinePlugin({ STRICT_THIS: strict })]
+});
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	config({ iife: true }, true),
+	config({ iife: false, strictModuleExceptionHandling: true }, true),
+	config({ iife: false, strictModuleExceptionHandling: false }, false)
+];
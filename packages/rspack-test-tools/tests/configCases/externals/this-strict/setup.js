@@ -0,0 +1 @@
+globalThis.externalName = "this-strict";
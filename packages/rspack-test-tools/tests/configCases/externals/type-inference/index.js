@@ -0,0 +1,42 @@
+import fs from "fs";
+
+export function load() {
+	return import("foo");
+}
+
+export function loadWasm() {
+	return import("bar");
+}
+
+export function loadData() {
+	return import("data");
+}
+
+it("should only infer the external type from the extension with experiments.externals.typeInference", function () {
+	const source = fs.readFileSync(__filename, "utf-8");
+	const request = JSON.stringify("./foo" + ".mjs");
+	if (INFERENCE) {
+		expect(source).toContain(`import(${request})`);
+		expect(source).not.toContain(`require(${request})`);
+	} else {
+		expect(source).toContain(`require(${request})`);
+		expect(source).not.toContain(`import(${request})`);
+	}
+});
+
+it("should infer the wasm external type from the extension with experiments.externals.typeInference", function () {
+	const source = fs.readFileSync(__filename, "utf-8");
+	const request = JSON.stringify("./bar" + ".wasm");
+	if (INFERENCE) {
+		expect(source).toContain(`fetch(${request})`);
+		expect(source).not.toContain(`require(${request})`);
+	} else {
+		expect(source).toContain(`require(${request})`);
+		expect(source).not.toContain(`fetch(${request})`);
+	}
+});
+
+it("should not infer the external type of json requests", function () {
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toContain(`require(${JSON.stringify("./data" + ".json")})`);
+});
@@ -0,0 +1,24 @@
+const { DefinePlugin } = require("@rspack/core");
+
+const config = ({ externalsType, typeInference }) => ({
+	target: "node",
+	externals: {
+		foo: "./foo.mjs",
+		bar: "./bar.wasm",
+		data: "./data.json"
+	},
+	externalsType,
+	experiments: {
+		externals: {
+			typeInference
+		}
+	},
+	plugins: [new DefinePlugin({ INFERENCE: typeInference })]
+});
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	// an explicit externalsType is used for `.mjs` requests without a prefix
+	config({ externalsType: "commonjs", typeInference: false }),
+	config({ externalsType: "commonjs", typeInference: true })
+];
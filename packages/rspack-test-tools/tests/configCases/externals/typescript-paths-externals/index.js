@@ -0,0 +1,17 @@
+import fs from "fs";
+import path from "path";
+
+it("should externalize tsconfig paths mapping to URLs with import()", function () {
+	// never called, the URLs cannot be loaded in the test
+	const load = () => Promise.all([import("$api"), import("$api/users")]);
+	expect(typeof load).toBe("function");
+
+	const source = fs
+		.readdirSync(__dirname)
+		.filter(file => file.endsWith(".js"))
+		.map(file => fs.readFileSync(path.join(__dirname, file), "utf-8"))
+		.join("\n");
+	// split the requests so that these assertions do not match themselves
+	expect(source).toContain('import("https://api.example.com/' + 'bundle.js")');
+	expect(source).toContain('import("https://api.example.com/' + 'users.js")');
+});
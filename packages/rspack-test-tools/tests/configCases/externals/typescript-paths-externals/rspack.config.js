@@ -0,0 +1,12 @@
+const path = require("path");
+const { TypeScriptPathsExternalsRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	plugins: [
+		new TypeScriptPathsExternalsRspackPlugin({
+			configFile: path.resolve(__dirname, "tsconfig.json")
+		})
+	]
+};
@@ -0,0 +1,21 @@
+import fs from "fs";
+import path from "path";
+import { fileURLToPath } from "url";
+
+it("should shorten the variable names of umd externals", function () {
+	expect(typeof fileURLToPath).toBe("function");
+
+	const read = file => fs.readFileSync(path.resolve(__dirname, file), "utf-8");
+	const plain = read("bundle0.js");
+	const minified = read("bundle1.js");
+	const names = plain.match(/__WEBPACK_EXTERNAL_MODULE_\w+?__/g);
+	// each external is a parameter of the umd factory and is read by its module
+	expect(names).toHaveLength(6);
+	expect(minified).not.toMatch(/__WEBPACK_EXTERNAL_MODULE_\w+__/);
+	for (const index of [0, 1, 2]) {
+		expect(minified).toMatch(new RegExp(`\\b_w${index}\\b`));
+	}
+	// the bundles only differ in the names, each shortened to the 3 characters of `_w<index>`
+	const saved = names.reduce((bytes, name) => bytes + name.length - 3, 0);
+	expect(plain.length - minified.length).toBe(saved);
+});
@@ -0,0 +1,12 @@
+const config = minifyExternals => ({
+	output: {
+		libraryTarget: "umd"
+	},
+	externals: ["fs", "path", "url"],
+	optimization: {
+		minifyExternals
+	}
+});
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [config(false), config(true)];
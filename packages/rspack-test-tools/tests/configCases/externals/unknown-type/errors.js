@@ -0,0 +1,3 @@
+module.exports = [
+	[/Unknown external type "commmonjs" of external "lodash"\. Did you mean "commonjs"\?/]
+];
@@ -0,0 +1,7 @@
+function load() {
+	return require("lodash");
+}
+
+it("should report unknown external types", function () {
+	expect(typeof load).toBe("function");
+});
@@ -0,0 +1,6 @@
+import fs from "fs";
+
+it("should not emit externals that are configured but never imported", function () {
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).not.toContain("module.exports = " + "Unused;");
+});
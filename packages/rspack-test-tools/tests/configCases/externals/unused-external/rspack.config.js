@@ -0,0 +1,30 @@
+const { ExternalModule } = require("@rspack/core");
+
+const PLUGIN_NAME = "plugin";
+
+class Plugin {
+	/**
+	 * @param {import("@rspack/core").Compiler} compiler
+	 */
+	apply(compiler) {
+		compiler.hooks.compilation.tap(PLUGIN_NAME, compilation => {
+			compilation.hooks.afterSeal.tap(PLUGIN_NAME, () => {
+				expect(compilation.getExternalModule("unused")).toBe(undefined);
+				expect(
+					Array.from(compilation.modules).some(
+						module => module instanceof ExternalModule
+					)
+				).toBe(false);
+			});
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externalsType: "var",
+	externals: {
+		unused: "Unused"
+	},
+	plugins: [new Plugin()]
+};
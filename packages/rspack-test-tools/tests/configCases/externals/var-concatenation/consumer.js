@@ -0,0 +1,3 @@
+import globalProcess from "global-process";
+
+export const platform = globalProcess.platform;
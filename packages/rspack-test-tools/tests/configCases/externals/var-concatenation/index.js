@@ -0,0 +1,11 @@
+import fs from "fs";
+import path from "path";
+import { platform } from "./consumer";
+
+it("should inline var externals into the concatenated consumer", function () {
+	expect(platform).toBe(process.platform);
+
+	const js = fs.readFileSync(path.resolve(__dirname, "bundle0.js"), "utf-8");
+	expect(js).not.toContain("module.exports = " + "process;");
+	expect(js).toMatch(/(var|const) \w+ = process;/);
+});
@@ -0,0 +1,12 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externalsType: "var",
+	externals: {
+		"global-process": "process"
+	},
+	optimization: {
+		concatenateModules: true,
+		providedExports: true,
+		usedExports: true
+	}
+};
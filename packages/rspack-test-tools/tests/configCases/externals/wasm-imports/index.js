@@ -0,0 +1,6 @@
+import "./setup";
+import { getResult } from "./wasm.wasm";
+
+it("should provide externals to the imports of WebAssembly modules", function () {
+	expect(getResult(1)).toBe(23);
+});
@@ -0,0 +1,10 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: {
+		"./module": "var wasmModule",
+		"./module2": "var wasmModule2"
+	},
+	experiments: {
+		asyncWebAssembly: true
+	}
+};
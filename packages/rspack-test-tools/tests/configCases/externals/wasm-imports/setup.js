@@ -0,0 +1,2 @@
+global.wasmModule = { getNumber: () => 20 };
+global.wasmModule2 = { getNumber: () => 3 };
@@ -0,0 +1,20 @@
+import fs from "fs";
+import path from "path";
+
+function loadMath() {
+	return import("math-wasm");
+}
+
+it("should instantiate wasm externals with WebAssembly.instantiateStreaming", function () {
+	expect(typeof loadMath).toBe("function");
+
+	const source = fs
+		.readdirSync(__dirname)
+		.filter(file => file.endsWith(".js"))
+		.map(file => fs.readFileSync(path.resolve(__dirname, file), "utf-8"))
+		.join("\n");
+	expect(source).toContain(
+		'WebAssembly.instantiateStreaming(fetch("https://cdn.example.com/math.wasm"))'
+	);
+	expect(source).toContain("return result.instance.exports;");
+});
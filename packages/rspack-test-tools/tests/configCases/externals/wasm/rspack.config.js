@@ -0,0 +1,6 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: {
+		"math-wasm": "wasm https://cdn.example.com/math.wasm"
+	}
+};
@@ -0,0 +1,20 @@
+import fs from "node:fs";
+import url from "node:url";
+import path from "node:path";
+
+export * from "external-path";
+
+it("should copy star re-exported module externals when another chunk imports the entry", async function () {
+	const lazy = await import(/* webpackChunkName: "lazy" */ "./lazy");
+	expect(lazy.join).toBe(path.join);
+
+	const __filename = url.fileURLToPath(import.meta.url);
+	const source = fs.readFileSync(
+		path.join(
+			__filename,
+			"../../../../js/config/library/esm-star-reexport-external-imported/bundle0.mjs"
+		),
+		"utf-8"
+	);
+	expect(source).toContain("__WEBPACK_REEXPORT_OBJECT__");
+});
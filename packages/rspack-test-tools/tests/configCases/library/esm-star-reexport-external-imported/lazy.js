@@ -0,0 +1,3 @@
+import * as entry from "./index";
+
+export const join = entry.join;
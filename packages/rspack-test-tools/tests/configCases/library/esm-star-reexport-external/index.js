@@ -0,0 +1,18 @@
+import fs from "node:fs";
+import url from "node:url";
+import path from "node:path";
+
+export * from "external-path";
+
+it("should re-export star re-exported module externals", function () {
+	const __filename = url.fileURLToPath(import.meta.url);
+	const source = fs.readFileSync(
+		path.join(
+			__filename,
+			"../../../../js/config/library/esm-star-reexport-external/bundle0.mjs"
+		),
+		"utf-8"
+	);
+	expect(source).toContain('export * from "node:path";');
+	expect(source).not.toContain("__WEBPACK_REEXPORT_OBJECT__");
+});
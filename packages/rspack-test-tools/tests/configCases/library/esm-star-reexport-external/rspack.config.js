@@ -0,0 +1,17 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "development",
+	output: {
+		libraryTarget: "module",
+		iife: false,
+		chunkFormat: "module",
+		filename: "bundle0.mjs"
+	},
+	externals: {
+		"external-path": "module node:path"
+	},
+	experiments: {
+		outputModule: true
+	},
+	target: "node"
+};
@@ -0,0 +1,3 @@
+import lib from "external-lib";
+
+console.log(lib);
@@ -0,0 +1,3 @@
+it("should inherit externals in child compilers", () => {
+	expect(1).toBe(1);
+});
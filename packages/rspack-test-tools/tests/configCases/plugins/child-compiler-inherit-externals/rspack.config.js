@@ -0,0 +1,43 @@
+const path = require("path");
+const assert = require("assert");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	externals: {
+		"external-lib": "ExternalLib"
+	},
+	plugins: [
+		{
+			apply(compiler) {
+				compiler.hooks.make.tapAsync("child", (compilation, callback) => {
+					const childCompiler = compilation.createChildCompiler(
+						"child",
+						{
+							filename: "child.js"
+						},
+						[
+							new compiler.webpack.EntryPlugin(
+								compiler.context,
+								path.resolve(__dirname, "./child.js"),
+								{ name: "child" }
+							)
+						],
+						{ inheritParentExternals: true }
+					);
+					childCompiler.compile((err, result) => {
+						if (err) return callback(err);
+						assert(result.errors.length === 0);
+						const asset = result.getAsset("child.js");
+						assert(
+							asset.source
+								.source()
+								.toString()
+								.includes("module.exports = ExternalLib;")
+						);
+						callback();
+					});
+				});
+			}
+		}
+	]
+};
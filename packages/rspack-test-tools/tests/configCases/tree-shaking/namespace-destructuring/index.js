@@ -0,0 +1,8 @@
+import * as lib from "./lib";
+
+const { used, unusedUsed } = lib;
+
+it("should only mark destructured properties of a namespace import as used", () => {
+	expect(used).toBe(1);
+	expect(unusedUsed).toBe(false);
+});
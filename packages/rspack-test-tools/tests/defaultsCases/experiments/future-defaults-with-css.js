@@ -15,9 +15,10 @@ module.exports = {
 		@@ ... @@
 		-     "asyncWebAssembly": false,
 		-     "css": undefined,
-		-     "futureDefaults": false,
 		+     "asyncWebAssembly": true,
 		+     "css": false,
+		@@ ... @@
+		-     "futureDefaults": false,
 		+     "futureDefaults": true,
 		@@ ... @@
 		+       },
@@ -14,9 +14,10 @@ module.exports = {
 		@@ ... @@
 		-     "asyncWebAssembly": false,
 		-     "css": undefined,
-		-     "futureDefaults": false,
 		+     "asyncWebAssembly": true,
 		+     "css": true,
+		@@ ... @@
+		-     "futureDefaults": false,
 		+     "futureDefaults": true,
 		@@ ... @@
 		+       },
@@ -0,0 +1 @@
+module.exports = [require("external-a"), require("external-b")];
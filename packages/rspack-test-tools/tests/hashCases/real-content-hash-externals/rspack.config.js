@@ -0,0 +1,48 @@
+const path = require("path");
+const base = {
+	mode: "production",
+	entry: "./src/index.js",
+	devtool: false,
+	output: {
+		filename: "[name].[contenthash].js"
+	},
+	optimization: {
+		realContentHash: true
+	},
+	stats: "normal",
+	context: __dirname
+};
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		...base,
+		output: {
+			...base.output,
+			path: path.resolve(__dirname, "./dist/v1")
+		},
+		externals: {
+			lib: "var 'https://cdn.example.com/lib@1.0.0/index.js'"
+		}
+	},
+	{
+		...base,
+		output: {
+			...base.output,
+			path: path.resolve(__dirname, "./dist/v1-copy")
+		},
+		externals: {
+			lib: "var 'https://cdn.example.com/lib@1.0.0/index.js'"
+		}
+	},
+	{
+		...base,
+		output: {
+			...base.output,
+			path: path.resolve(__dirname, "./dist/v2")
+		},
+		externals: {
+			lib: "var 'https://cdn.example.com/lib@2.0.0/index.js'"
+		}
+	}
+];
@@ -0,0 +1,13 @@
+/** @type {import('../../..').THashCaseConfig} */
+module.exports = {
+	validate(stats) {
+		const v1 = stats.stats[0].toJson({ assets: true });
+		const v1Copy = stats.stats[1].toJson({ assets: true });
+		const v2 = stats.stats[2].toJson({ assets: true });
+
+		// unchanged externals keep the real content hash
+		expect(v1.assetsByChunkName.main).toEqual(v1Copy.assetsByChunkName.main);
+		// the generated code of a changed external contributes to it
+		expect(v1.assetsByChunkName.main).not.toEqual(v2.assetsByChunkName.main);
+	}
+};
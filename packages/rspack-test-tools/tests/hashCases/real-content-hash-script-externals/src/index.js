@@ -0,0 +1 @@
+import("./async").then(({ default: lib }) => lib);
@@ -0,0 +1,13 @@
+/** @type {import('../../..').THashCaseConfig} */
+module.exports = {
+	validate(stats) {
+		const [v1, v1Copy, v2] = stats.stats.map(s =>
+			s.toJson({ assets: true }).assets.map(asset => asset.name).sort()
+		);
+
+		// unchanged script urls keep the real content hashes
+		expect(v1).toEqual(v1Copy);
+		// the chunk loading the external from a changed url gets a new hash
+		expect(v1.filter(name => !v2.includes(name))).not.toHaveLength(0);
+	}
+};
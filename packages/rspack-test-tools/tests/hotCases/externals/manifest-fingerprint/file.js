@@ -0,0 +1,3 @@
+module.exports = require("lib-v1");
+---
+module.exports = require("lib-v2");
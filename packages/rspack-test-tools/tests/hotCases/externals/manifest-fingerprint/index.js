@@ -0,0 +1,27 @@
+import path from "path";
+import fs from "fs";
+
+let value = require("./file");
+const prevFullhash = __webpack_hash__;
+
+it("should list the changed external in the hot update manifest", done => {
+	expect(value).toBe(1);
+	module.hot.accept("./file", () => {
+		value = require("./file");
+	});
+	NEXT(
+		require("../../update")(done, true, () => {
+			expect(value).toBe(2);
+			const manifest = JSON.parse(
+				fs.readFileSync(
+					path.join(__dirname, `main.${prevFullhash}.hot-update.json`),
+					"utf-8"
+				)
+			);
+			const fingerprints = Object.values(manifest.externals);
+			expect(fingerprints).toHaveLength(1);
+			expect(fingerprints[0]).toMatch(/^[0-9a-f]{16}$/);
+			done();
+		})
+	);
+});
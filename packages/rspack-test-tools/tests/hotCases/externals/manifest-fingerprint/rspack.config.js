@@ -0,0 +1,16 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js"
+	},
+	externals: {
+		"lib-v1": "var 1",
+		"lib-v2": "var 2"
+	},
+	externalsPresets: {
+		node: true
+	},
+	node: {
+		__dirname: false
+	}
+};
@@ -0,0 +1,34 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should list externals in chunk modules with their external type",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: {
+				main: "./fixtures/abc"
+			},
+			externals: {
+				"./a": "commonjs ./a",
+				"./b": "var b"
+			}
+		};
+	},
+	async check(stats) {
+		const { chunks } = stats?.toJson({
+			all: false,
+			chunks: true,
+			chunkModules: true
+		});
+		const externals = chunks[0].modules
+			.filter(module => module.externalType)
+			.map(({ name, externalType }) => ({ name, externalType }))
+			.sort((a, b) => a.name.localeCompare(b.name));
+		expect(externals).toEqual([
+			{ name: 'external "./a"', externalType: "commonjs" },
+			{ name: 'external "b"', externalType: "var" }
+		]);
+		expect(
+			chunks[0].modules.find(module => module.name === "./fixtures/c.js")
+		).not.toHaveProperty("externalType");
+	}
+};
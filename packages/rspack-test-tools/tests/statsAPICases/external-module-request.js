@@ -0,0 +1,52 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description: "should add the external type and request to module stats of externals",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: {
+				main: "./fixtures/abc"
+			},
+			externals: {
+				"./a": "commonjs ./a",
+				"./b": {
+					commonjs: "b-commonjs",
+					amd: "b-amd"
+				}
+			},
+			externalsType: "commonjs"
+		};
+	},
+	async check(stats) {
+		const { modules } = stats?.compilation.getStats().toJson({
+			all: false,
+			modules: true
+		});
+		const externals = modules
+			.filter(module => module.externalType)
+			.map(({ type, moduleType, externalType, request }) => ({
+				type,
+				moduleType,
+				externalType,
+				request
+			}))
+			.sort((a, b) => a.request.localeCompare(b.request));
+		expect(externals).toEqual([
+			{
+				type: "module",
+				moduleType: "javascript/auto",
+				externalType: "commonjs",
+				request: "./a"
+			},
+			{
+				type: "module",
+				moduleType: "javascript/auto",
+				externalType: "commonjs",
+				request: "b-commonjs"
+			}
+		]);
+		const normal = modules.find(module => module.name === "./fixtures/c.js");
+		expect(normal).not.toHaveProperty("externalType");
+		expect(normal).not.toHaveProperty("request");
+	}
+};
@@ -0,0 +1,18 @@
+import fs from "fs";
+
+it("should update the CDN url when the package is upgraded", function () {
+	// never called, the url cannot be loaded in the test
+	const load = () => import("fake-lib");
+	expect(typeof load).toBe("function");
+
+	const source = fs.readFileSync(__filename, "utf-8");
+	// split the requests so that these assertions do not match themselves
+	switch (WATCH_STEP) {
+		case "0":
+			expect(source).toContain('import("https://esm.sh/' + 'fake-lib@1.0.0")');
+			break;
+		case "1":
+			expect(source).toContain('import("https://esm.sh/' + 'fake-lib@2.0.0")');
+			break;
+	}
+});
@@ -0,0 +1,12 @@
+import styles from "./button.module.scss";
+
+it("should update the class names when the manifest changes", function () {
+	switch (WATCH_STEP) {
+		case "0":
+			expect(styles.container).toBe("container__abc123");
+			break;
+		case "1":
+			expect(styles.container).toBe("container__xyz789");
+			break;
+	}
+});
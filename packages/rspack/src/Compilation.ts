@@ -22,11 +22,11 @@ import type { Source } from "webpack-sources";
 import { Chunk } from "./Chunk";
 import { ChunkGraph } from "./ChunkGraph";
 import { ChunkGroup } from "./ChunkGroup";
-import type { Compiler } from "./Compiler";
+import type { ChildCompilerOptions, Compiler } from "./Compiler";
 import type { ContextModuleFactory } from "./ContextModuleFactory";
 import { Entrypoint } from "./Entrypoint";
 import { cutOffLoaderExecution } from "./ErrorHelpers";
-import { type CodeGenerationResult, Module } from "./Module";
+import { type CodeGenerationResult, type ExternalModule, Module } from "./Module";
 import type { NormalModuleFactory } from "./NormalModuleFactory";
 import type { ResolverFactory } from "./ResolverFactory";
 import { JsRspackDiagnostic, type RspackError } from "./RspackError";
@@ -439,6 +439,16 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 		);
 	}
 
+	/**
+	 * Get the external module created for `request`, optionally only the one of the given external type.
+	 */
+	getExternalModule(request: string, type?: string): ExternalModule | undefined {
+		const module = this.#inner.getExternalModule(request, type);
+		return module
+			? (Module.__from_binding(module, this) as ExternalModule)
+			: undefined;
+	}
+
 	get chunks(): ReadonlySet<Chunk> {
 		return memoizeValue(() => new Set(this.__internal__getChunks()));
 	}
@@ -1093,7 +1103,8 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 	createChildCompiler(
 		name: string,
 		outputOptions: OutputNormalized,
-		plugins: RspackPluginInstance[]
+		plugins: RspackPluginInstance[],
+		childOptions?: ChildCompilerOptions
 	) {
 		const idx = this.childrenCounters[name] || 0;
 		this.childrenCounters[name] = idx + 1;
@@ -1102,7 +1113,8 @@ BREAKING CHANGE: Asset processing hooks in Compilation has been merged into a si
 			name,
 			idx,
 			outputOptions,
-			plugins
+			plugins,
+			childOptions
 		);
 	}
 
@@ -81,6 +81,14 @@ export interface AssetEmittedInfo {
 	compilation: Compilation;
 }
 
+export interface ChildCompilerOptions {
+	/**
+	 * Apply the externals of the parent compiler to the child compiler.
+	 * @default false
+	 */
+	inheritParentExternals?: boolean;
+}
+
 class Compiler {
 	#instance?: binding.Rspack;
 	#initial: boolean;
@@ -531,7 +539,8 @@ class Compiler {
 		compilerName: string,
 		compilerIndex: number,
 		outputOptions: OutputNormalized,
-		plugins: RspackPluginInstance[]
+		plugins: RspackPluginInstance[],
+		childOptions: ChildCompilerOptions = {}
 	): Compiler {
 		const options: RspackOptionsNormalized = {
 			...this.options,
@@ -581,7 +590,10 @@ class Compiler {
 		childCompiler.#builtinPlugins = [
 			...childCompiler.#builtinPlugins,
 			...this.#builtinPlugins.filter(
-				plugin => plugin.canInherentFromParent === true
+				plugin =>
+					plugin.canInherentFromParent === true ||
+					(childOptions.inheritParentExternals &&
+						plugin.name === binding.BuiltinPluginName.ExternalsPlugin)
 			)
 		];
 
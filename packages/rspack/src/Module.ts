@@ -240,7 +240,10 @@ export class Module {
 	static __from_binding(
 		module: JsModule | ModuleDTO,
 		compilation?: Compilation
-	) {
+	): Module {
+		if ("externalType" in module && module.externalType !== undefined) {
+			return new ExternalModule(module, compilation);
+		}
 		return new Module(module, compilation);
 	}
 
@@ -299,6 +302,29 @@ export class Module {
 	}
 }
 
+export type ExternalRequest =
+	| string
+	| string[]
+	| Record<string, string | string[]>
+	| Array<[string, string | string[]]>;
+
+export class ExternalModule extends Module {
+	/**
+	 * The request loaded at runtime, e.g. the global variable name or the module name.
+	 * An object for `umd` externals, or `[type, request]` pairs for externals with fallbacks.
+	 */
+	externalRequest: Readonly<ExternalRequest>;
+	externalType: string;
+	userRequest: string;
+
+	constructor(module: ModuleDTO, compilation?: Compilation) {
+		super(module, compilation);
+		this.externalRequest = module.externalRequest!;
+		this.externalType = module.externalType!;
+		this.userRequest = module.userRequest!;
+	}
+}
+
 export class CodeGenerationResult {
 	#inner: JsCodegenerationResult;
 
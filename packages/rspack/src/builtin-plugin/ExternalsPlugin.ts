@@ -8,12 +8,17 @@ import { create } from "./base";
 
 export const ExternalsPlugin = create(
 	BuiltinPluginName.ExternalsPlugin,
-	(type: string, externals: Externals): RawExternalsPluginOptions => {
+	(
+		type: string,
+		externals: Externals,
+		requestTransformer?: (request: string) => string
+	): RawExternalsPluginOptions => {
 		return {
 			type,
 			externals: (Array.isArray(externals) ? externals : [externals])
 				.filter(Boolean)
-				.map(getRawExternalItem)
+				.map(getRawExternalItem),
+			requestTransformer
 		};
 	}
 );
@@ -29,15 +34,20 @@ function getRawExternalItem(item: ExternalItem | undefined): RawExternalItem {
 	}
 
 	if (typeof item === "function") {
-		return async ctx => {
+		return async ({ resolveData, ...data }) => {
 			return await new Promise((resolve, reject) => {
-				const promise = item(ctx, (err, result, type) => {
-					if (err) reject(err);
-					resolve({
-						result: getRawExternalItemValueFormFnResult(result),
-						externalType: type
-					});
-				}) as Promise<ExternalItemValue>;
+				// called without a receiver, so `this` is always `undefined`
+				const promise = item(
+					data,
+					(err, result, type) => {
+						if (err) return reject(err);
+						resolve({
+							result: getRawExternalItemValueFormFnResult(result),
+							externalType: type
+						});
+					},
+					resolveData
+				) as Promise<ExternalItemValue | undefined>;
 				if (promise?.then) {
 					promise.then(
 						result =>
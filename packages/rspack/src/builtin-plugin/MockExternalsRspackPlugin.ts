@@ -0,0 +1,24 @@
+import {
+	BuiltinPluginName,
+	type RawMockExternalsPluginOptions
+} from "@rspack/binding";
+
+import { create } from "./base";
+
+export type MockExternalsRspackPluginOptions = {
+	/** JSON file mapping the requests of externals to the source of their mocks */
+	path: string;
+	/** Whether to replace the externals with their mocks, defaults to `RSPACK_TEST_MODE=1` */
+	enabled?: boolean;
+};
+
+export const MockExternalsRspackPlugin = create(
+	BuiltinPluginName.MockExternalsRspackPlugin,
+	(options: MockExternalsRspackPluginOptions): RawMockExternalsPluginOptions => {
+		return {
+			path: options.path,
+			enabled: options.enabled ?? process.env.RSPACK_TEST_MODE === "1"
+		};
+	},
+	"compilation"
+);
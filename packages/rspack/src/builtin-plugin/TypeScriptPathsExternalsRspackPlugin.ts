@@ -0,0 +1,23 @@
+import {
+	BuiltinPluginName,
+	type RawTypeScriptPathsExternalsPluginOptions
+} from "@rspack/binding";
+
+import { create } from "./base";
+
+export type TypeScriptPathsExternalsRspackPluginOptions = {
+	/** tsconfig.json whose `compilerOptions.paths` entries mapping to a URL become `import` externals */
+	configFile: string;
+};
+
+export const TypeScriptPathsExternalsRspackPlugin = create(
+	BuiltinPluginName.TypeScriptPathsExternalsRspackPlugin,
+	(
+		options: TypeScriptPathsExternalsRspackPluginOptions
+	): RawTypeScriptPathsExternalsPluginOptions => {
+		return {
+			configFile: options.configFile
+		};
+	},
+	"compilation"
+);
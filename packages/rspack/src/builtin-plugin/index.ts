@@ -24,6 +24,7 @@ export * from "./EnsureChunkConditionsPlugin";
 export * from "./EntryPlugin";
 export * from "./EvalDevToolModulePlugin";
 export * from "./EvalSourceMapDevToolPlugin";
+export * from "./ExternalExportsValidatorRspackPlugin";
 export * from "./ExternalsPlugin";
 export * from "./FileUriPlugin";
 export * from "./FlagDependencyExportsPlugin";
@@ -38,6 +39,7 @@ export * from "./JsLoaderRspackPlugin";
 export * from "./JsonModulesPlugin";
 export * from "./lazy-compilation/plugin";
 export * from "./LimitChunkCountPlugin";
+export * from "./MockExternalsRspackPlugin";
 export * from "./MangleExportsPlugin";
 export * from "./MergeDuplicateChunksPlugin";
 export * from "./ModuleChunkFormatPlugin";
@@ -59,6 +61,7 @@ export * from "./SourceMapDevToolPlugin";
 export * from "./SplitChunksPlugin";
 export * from "./LightningCssMiminizerRspackPlugin";
 export * from "./SwcJsMinimizerPlugin";
+export * from "./TypeScriptPathsExternalsRspackPlugin";
 export * from "./WarnCaseSensitiveModulesPlugin";
 export * from "./WebWorkerTemplatePlugin";
 export * from "./WorkerPlugin";
@@ -13,6 +13,7 @@ import {
 	type RawCssModuleGeneratorOptions,
 	type RawCssModuleParserOptions,
 	type RawCssParserOptions,
+	type RawExternalsExperiments,
 	type RawFuncUseCtx,
 	type RawGeneratorOptions,
 	type RawIncremental,
@@ -56,6 +57,7 @@ import type {
 	CssAutoGeneratorOptions,
 	CssGeneratorOptions,
 	CssParserOptions,
+	ExternalsExperiments,
 	GeneratorOptionsByModuleType,
 	Incremental,
 	JavascriptParserOptions,
@@ -123,6 +125,10 @@ export const getRawOptions = (
 		profile: options.profile!,
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
 		bail: options.bail!,
+		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
+		perChunkExternalOverrides: options.perChunkExternalOverrides!,
+		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
+		validateSubpathExports: options.validateSubpathExports!,
 		__references: {}
 	};
 };
@@ -867,6 +873,7 @@ function getRawOptimization(
 		providedExports: optimization.providedExports,
 		innerGraph: optimization.innerGraph,
 		concatenateModules: !!optimization.concatenateModules,
+		minifyExternals: !!optimization.minifyExternals,
 		mangleExports: String(optimization.mangleExports)
 	};
 }
@@ -880,19 +887,34 @@ function getRawSnapshotOptions(
 function getRawExperiments(
 	experiments: ExperimentsNormalized
 ): RawOptions["experiments"] {
-	const { topLevelAwait, layers, incremental, rspackFuture } = experiments;
+	const { topLevelAwait, layers, incremental, rspackFuture, externals } =
+		experiments;
 	assert(
 		!isNil(topLevelAwait) &&
 			!isNil(rspackFuture) &&
 			!isNil(layers) &&
-			!isNil(incremental)
+			!isNil(incremental) &&
+			!isNil(externals)
 	);
 
 	return {
 		layers,
 		topLevelAwait,
 		incremental: getRawIncremental(incremental),
-		rspackFuture: getRawRspackFutureOptions(rspackFuture)
+		rspackFuture: getRawRspackFutureOptions(rspackFuture),
+		externals: getRawExternalsExperiments(externals)
+	};
+}
+
+function getRawExternalsExperiments(
+	externals: ExternalsExperiments
+): RawExternalsExperiments {
+	return {
+		typeInference: externals.typeInference!,
+		integrity: externals.integrity!,
+		runtime: externals.runtime!,
+		expectedVersion: externals.expectedVersion!,
+		cspSafe: externals.cspSafe!
 	};
 }
 
@@ -83,6 +83,8 @@ export const applyRspackOptionsDefaults = (
 	D(options, "profile", false);
 	// IGNORE(bail): bail is default to false in webpack, but it's set in `Compilation`
 	D(options, "bail", false);
+	D(options, "perChunkExternalOverrides", {});
+	D(options, "validateSubpathExports", false);
 
 	// IGNORE(cache): cache is default to { type: "memory" } in webpack when the mode is development,
 	// but Rspack currently does not support this option
@@ -213,6 +215,15 @@ const applyExperimentsDefaults = (experiments: ExperimentsNormalized) => {
 		D(experiments.incremental, "modulesCodegen", false);
 		D(experiments.incremental, "modulesRuntimeRequirements", false);
 	}
+	// IGNORE(experiments.externals): Rspack specific configuration for externals
+	D(experiments, "externals", {});
+	if (typeof experiments.externals === "object") {
+		D(experiments.externals, "typeInference", false);
+		D(experiments.externals, "integrity", {});
+		D(experiments.externals, "runtime", {});
+		D(experiments.externals, "expectedVersion", {});
+		D(experiments.externals, "cspSafe", false);
+	}
 	// IGNORE(experiments.rspackFuture): Rspack specific configuration
 	D(experiments, "rspackFuture", {});
 	// rspackFuture.bundlerInfo default value is applied after applyDefaults
@@ -36,6 +36,7 @@ import type {
 	EntryStatic,
 	Environment,
 	Externals,
+	ExternalsExperiments,
 	ExternalsPresets,
 	ExternalsType,
 	Filename,
@@ -66,6 +67,7 @@ import type {
 	OutputModule,
 	ParserOptionsByModuleType,
 	Path,
+	PerChunkExternalOverrides,
 	Performance,
 	Plugins,
 	Profile,
@@ -82,6 +84,7 @@ import type {
 	Target,
 	TrustedTypes,
 	UniqueName,
+	ValidateSubpathExports,
 	WasmLoading,
 	Watch,
 	WatchOptions,
@@ -324,13 +327,16 @@ export const getNormalizedRspackOptions = (
 							modulesRuntimeRequirements: true
 						} satisfies Incremental)
 					: options
-			)
+			),
+			externals: cloneObject(experiments.externals)
 		})),
 		watch: config.watch,
 		watchOptions: cloneObject(config.watchOptions),
 		devServer: config.devServer,
 		profile: config.profile,
-		bail: config.bail
+		bail: config.bail,
+		perChunkExternalOverrides: cloneObject(config.perChunkExternalOverrides),
+		validateSubpathExports: config.validateSubpathExports
 	};
 };
 
@@ -538,6 +544,7 @@ export interface ExperimentsNormalized {
 	incremental?: false | Incremental;
 	futureDefaults?: boolean;
 	rspackFuture?: RspackFutureOptions;
+	externals?: ExternalsExperiments;
 }
 
 export type IgnoreWarningsNormalized = ((
@@ -582,4 +589,6 @@ export interface RspackOptionsNormalized {
 	performance?: Performance;
 	profile?: Profile;
 	bail?: Bail;
+	perChunkExternalOverrides?: PerChunkExternalOverrides;
+	validateSubpathExports?: ValidateSubpathExports;
 }
@@ -1290,7 +1290,24 @@ export type ExternalsType =
 	| "import"
 	| "module-import"
 	| "script"
-	| "node-commonjs";
+	| "node-commonjs"
+	| "import-sync"
+	| "wasm"
+	| "import-lazy"
+	| "css-module-import"
+	| "import-script"
+	| "import-css-worklet"
+	| "entry-import"
+	| "micro-frontend"
+	| "dynamic-require"
+	| "federation-delegate"
+	| "bare-specifier"
+	| "unpkg"
+	| "esm-sh"
+	| "css-property"
+	| "inline-umd"
+	| "remote-entry-v2"
+	| "sass-module";
 //#endregion
 
 //#region Externals
@@ -1319,9 +1336,29 @@ export type ExternalItemFunctionData = {
 	request?: string;
 	contextInfo?: {
 		issuer: string;
+		issuerLayer?: string;
 	};
 };
 
+/**
+ * Resolve data passed as the third argument when a function is set for 'externals'.
+ */
+export type ExternalItemResolveData = {
+	context: string;
+	dependencyType: string;
+	request: string;
+	contextInfo: {
+		issuer: string;
+		issuerLayer?: string;
+	};
+	/** The request without its query and fragment. */
+	path: string;
+	/** The query of the request including the leading `?`, or an empty string. */
+	query: string;
+	/** The fragment of the request including the leading `#`, or an empty string. */
+	fragment: string;
+};
+
 /**
  * Prevent bundling of certain imported package and instead retrieve these external dependencies at runtime.
  *
@@ -1342,12 +1379,15 @@ export type ExternalItem =
 	| ((
 			data: ExternalItemFunctionData,
 			callback: (
-				err?: Error,
+				err?: Error | null,
 				result?: ExternalItemValue,
 				type?: ExternalsType
-			) => void
+			) => void,
+			resolveData: ExternalItemResolveData
 	  ) => void)
-	| ((data: ExternalItemFunctionData) => Promise<ExternalItemValue>);
+	| ((
+			data: ExternalItemFunctionData
+	  ) => Promise<ExternalItemValue | undefined>);
 
 /**
  * Prevent bundling of certain imported packages and instead retrieve these external dependencies at runtime.
@@ -2217,6 +2257,12 @@ export type Optimization = {
 	 */
 	concatenateModules?: boolean;
 
+	/**
+	 * Tells Rspack to replace the `__WEBPACK_EXTERNAL_MODULE_<id>__` variables of `amd`, `umd` and `system` externals with short names.
+	 * @default false
+	 */
+	minifyExternals?: boolean;
+
 	/**
 	 * Tells Rspack whether to perform a more detailed analysis of variable assignments.
 	 *
@@ -2399,6 +2445,38 @@ export type Incremental = {
 	modulesRuntimeRequirements?: boolean;
 };
 
+/**
+ * Options for externals.
+ */
+export type ExternalsExperiments = {
+	/**
+	 * Infer the type of an external without a type prefix from the file extension of its request.
+	 * @default false
+	 */
+	typeInference?: boolean;
+
+	/**
+	 * Subresource integrity hashes of `script` externals, keyed by the request as written in the source code.
+	 */
+	integrity?: Record<string, string>;
+
+	/**
+	 * Runtimes externals are loaded in, keyed by the request as written in the source code.
+	 */
+	runtime?: Record<string, string[]>;
+
+	/**
+	 * Semver ranges the `version` export of externals is checked against at runtime, keyed by the request as written in the source code.
+	 */
+	expectedVersion?: Record<string, string>;
+
+	/**
+	 * Read the globals of `window` and `self` externals through a guarded lookup.
+	 * @default false
+	 */
+	cspSafe?: boolean;
+};
+
 /**
  * Experimental features configuration.
  */
@@ -2450,5 +2528,9 @@ export type Experiments = {
 	 * Enable future Rspack features default options.
 	 */
 	rspackFuture?: RspackFutureOptions;
+	/**
+	 * Options for externals.
+	 */
+	externals?: ExternalsExperiments;
 };
 //#endregion
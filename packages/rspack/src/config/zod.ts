@@ -790,7 +790,24 @@ export const externalsType = z.enum([
 	"import",
 	"module-import",
 	"script",
-	"node-commonjs"
+	"node-commonjs",
+	"import-sync",
+	"wasm",
+	"import-lazy",
+	"css-module-import",
+	"import-script",
+	"import-css-worklet",
+	"entry-import",
+	"micro-frontend",
+	"dynamic-require",
+	"federation-delegate",
+	"bare-specifier",
+	"unpkg",
+	"esm-sh",
+	"css-property",
+	"inline-umd",
+	"remote-entry-v2",
+	"sass-module"
 ]) satisfies z.ZodType<t.ExternalsType>;
 //#endregion
 
@@ -813,11 +830,25 @@ const externalItemFunctionData = z.strictObject({
 	request: z.string().optional(),
 	contextInfo: z
 		.strictObject({
-			issuer: z.string()
+			issuer: z.string(),
+			issuerLayer: z.string().optional()
 		})
 		.optional()
 }) satisfies z.ZodType<t.ExternalItemFunctionData>;
 
+const externalItemResolveData = z.strictObject({
+	context: z.string(),
+	dependencyType: z.string(),
+	request: z.string(),
+	contextInfo: z.strictObject({
+		issuer: z.string(),
+		issuerLayer: z.string().optional()
+	}),
+	path: z.string(),
+	query: z.string(),
+	fragment: z.string()
+}) satisfies z.ZodType<t.ExternalItemResolveData>;
+
 const externalItem = z
 	.string()
 	.or(z.instanceof(RegExp))
@@ -830,18 +861,19 @@ const externalItem = z
 				z
 					.function()
 					.args(
-						z.instanceof(Error).optional(),
+						z.instanceof(Error).nullable().optional(),
 						externalItemValue.optional(),
 						externalsType.optional()
 					)
-					.returns(z.void())
+					.returns(z.void()),
+				externalItemResolveData
 			)
 	)
 	.or(
 		z
 			.function()
 			.args(externalItemFunctionData)
-			.returns(z.promise(externalItemValue))
+			.returns(z.promise(externalItemValue.optional()))
 	) satisfies z.ZodType<t.ExternalItem>;
 
 const externals = externalItem
@@ -1184,6 +1216,7 @@ const optimization = z.strictObject({
 	sideEffects: z.enum(["flag"]).or(z.boolean()).optional(),
 	providedExports: z.boolean().optional(),
 	concatenateModules: z.boolean().optional(),
+	minifyExternals: z.boolean().optional(),
 	innerGraph: z.boolean().optional(),
 	usedExports: z.enum(["global"]).or(z.boolean()).optional(),
 	mangleExports: z.enum(["size", "deterministic"]).or(z.boolean()).optional(),
@@ -1244,6 +1277,14 @@ const incremental = z.strictObject({
 	modulesRuntimeRequirements: z.boolean().optional()
 }) satisfies z.ZodType<t.Incremental>;
 
+const externalsExperiments = z.strictObject({
+	typeInference: z.boolean().optional(),
+	integrity: z.record(z.string()).optional(),
+	runtime: z.record(z.string().array()).optional(),
+	expectedVersion: z.record(z.string()).optional(),
+	cspSafe: z.boolean().optional()
+}) satisfies z.ZodType<t.ExternalsExperiments>;
+
 const experiments = z.strictObject({
 	lazyCompilation: z.boolean().optional().or(lazyCompilationOptions),
 	asyncWebAssembly: z.boolean().optional(),
@@ -1253,7 +1294,8 @@ const experiments = z.strictObject({
 	layers: z.boolean().optional(),
 	incremental: z.boolean().or(incremental).optional(),
 	futureDefaults: z.boolean().optional(),
-	rspackFuture: rspackFutureOptions.optional()
+	rspackFuture: rspackFutureOptions.optional(),
+	externals: externalsExperiments.optional()
 }) satisfies z.ZodType<t.Experiments>;
 //#endregion
 
@@ -1306,6 +1348,18 @@ const bail = z.boolean();
 export type Bail = z.infer<typeof bail>;
 //#endregion
 
+//#region PerChunkExternalOverrides
+const perChunkExternalOverrides = z.record(z.record(z.string()));
+export type PerChunkExternalOverrides = z.infer<
+	typeof perChunkExternalOverrides
+>;
+//#endregion
+
+//#region ValidateSubpathExports
+const validateSubpathExports = z.boolean();
+export type ValidateSubpathExports = z.infer<typeof validateSubpathExports>;
+//#endregion
+
 //#region Performance
 const performance = z
 	.strictObject({
@@ -1348,6 +1402,8 @@ export const rspackOptions = z.strictObject({
 	module: moduleOptions.optional(),
 	profile: profile.optional(),
 	bail: bail.optional(),
+	perChunkExternalOverrides: perChunkExternalOverrides.optional(),
+	validateSubpathExports: validateSubpathExports.optional(),
 	performance: performance.optional()
 });
 export type RspackOptions = z.infer<typeof rspackOptions>;
@@ -19,6 +19,7 @@ export type {
 } from "./Compilation";
 export { Compilation } from "./Compilation";
 export { Compiler } from "./Compiler";
+export type { ChildCompilerOptions } from "./Compiler";
 export type { MultiCompilerOptions, MultiRspackOptions } from "./MultiCompiler";
 export { MultiCompiler } from "./MultiCompiler";
 
@@ -28,6 +29,7 @@ export { RspackOptionsApply, RspackOptionsApply as WebpackOptionsApply };
 export type { Chunk } from "./Chunk";
 export type { ChunkGroup } from "./ChunkGroup";
 export type { Module, ResolveData } from "./Module";
+export { ExternalModule, type ExternalRequest } from "./Module";
 export { MultiStats } from "./MultiStats";
 export { NormalModule } from "./NormalModule";
 export type { NormalModuleFactory } from "./NormalModuleFactory";
@@ -104,6 +106,10 @@ export { ExternalsPlugin } from "./builtin-plugin";
 export { HotModuleReplacementPlugin } from "./builtin-plugin";
 export { NoEmitOnErrorsPlugin } from "./builtin-plugin";
 export { EnvironmentPlugin } from "./lib/EnvironmentPlugin";
+export {
+	ImportMapExternalsPlugin,
+	type ImportMapExternalsPluginOptions
+} from "./lib/ImportMapExternalsPlugin";
 export { LoaderOptionsPlugin } from "./lib/LoaderOptionsPlugin";
 export { LoaderTargetPlugin } from "./lib/LoaderTargetPlugin";
 export { NormalModuleReplacementPlugin } from "./lib/NormalModuleReplacementPlugin";
@@ -259,6 +265,11 @@ export { SourceMapDevToolPlugin } from "./builtin-plugin";
 export { EvalSourceMapDevToolPlugin } from "./builtin-plugin";
 export { EvalDevToolModulePlugin } from "./builtin-plugin";
 export { CssExtractRspackPlugin } from "./builtin-plugin";
+export { ExternalExportsValidatorRspackPlugin } from "./builtin-plugin";
+export { MockExternalsRspackPlugin } from "./builtin-plugin";
+export type { MockExternalsRspackPluginOptions } from "./builtin-plugin";
+export { TypeScriptPathsExternalsRspackPlugin } from "./builtin-plugin";
+export type { TypeScriptPathsExternalsRspackPluginOptions } from "./builtin-plugin";
 export { ContextReplacementPlugin } from "./builtin-plugin";
 
 ///// Rspack Postfixed Internal Loaders /////
@@ -0,0 +1,83 @@
+import fs from "node:fs";
+import * as NodePath from "node:path";
+
+import { ExternalsPlugin } from "../builtin-plugin";
+import WebpackError from "./WebpackError";
+
+import type { Compiler } from "../Compiler";
+
+export type ImportMapExternalsPluginOptions = {
+	/**
+	 * Path to the import map JSON file, resolved against `context`.
+	 */
+	path: string;
+};
+
+const PLUGIN_NAME = "ImportMapExternalsPlugin";
+
+/**
+ * Externalizes the bare specifiers mapped in the `imports` of a browser
+ * import map as `module` externals. Specifiers ending with `/` map every
+ * request under that prefix. The import map file is watched in watch mode.
+ */
+export class ImportMapExternalsPlugin {
+	imports: Record<string, string> = {};
+
+	constructor(public readonly options: ImportMapExternalsPluginOptions) {}
+
+	/**
+	 * Apply the plugin
+	 * @param compiler the compiler instance
+	 * @returns
+	 */
+	apply(compiler: Compiler) {
+		const importMapPath = NodePath.resolve(compiler.context, this.options.path);
+
+		compiler.hooks.thisCompilation.tap(PLUGIN_NAME, compilation => {
+			compilation.fileDependencies.add(importMapPath);
+			try {
+				const importMap = JSON.parse(fs.readFileSync(importMapPath, "utf-8"));
+				this.imports = importMap.imports ?? {};
+			} catch (e) {
+				this.imports = {};
+				const error = new WebpackError(
+					`${PLUGIN_NAME} - failed to read import map ${importMapPath}: ${
+						(e as Error).message
+					}`
+				);
+				error.name = "ImportMapError";
+				compilation.errors.push(error);
+			}
+		});
+
+		new ExternalsPlugin("module", ({ request }, callback) => {
+			const url = request ? this.resolve(request) : undefined;
+			if (url === undefined) return callback();
+			callback(null, url, "module");
+		}).apply(compiler);
+	}
+
+	/**
+	 * Resolve a specifier with the import map, preferring an exact match over
+	 * the longest matching prefix.
+	 * @param request the bare specifier
+	 * @returns the mapped url, or `undefined` if the specifier is not mapped
+	 */
+	resolve(request: string): string | undefined {
+		if (Object.prototype.hasOwnProperty.call(this.imports, request)) {
+			return this.imports[request];
+		}
+		let prefix: string | undefined;
+		for (const key of Object.keys(this.imports)) {
+			if (
+				key.endsWith("/") &&
+				request.startsWith(key) &&
+				(prefix === undefined || key.length > prefix.length)
+			) {
+				prefix = key;
+			}
+		}
+		if (prefix === undefined) return undefined;
+		return this.imports[prefix] + request.slice(prefix.length);
+	}
+}
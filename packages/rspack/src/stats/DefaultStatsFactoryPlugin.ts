@@ -1187,6 +1187,10 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			object.type = commonAttributes.type;
 			object.moduleType = commonAttributes.moduleType;
 			object.layer = commonAttributes.layer;
+			if (commonAttributes.externalType) {
+				object.externalType = commonAttributes.externalType;
+				object.request = commonAttributes.request;
+			}
 			object.size = commonAttributes.size;
 			const sizes = commonAttributes.sizes.map(({ sourceType, size }) => [
 				sourceType,
@@ -102,6 +102,8 @@ export type KnownStatsModule = {
 	type: string;
 	moduleType: string;
 	layer?: string;
+	externalType?: string;
+	request?: string;
 	identifier?: string;
 	name?: string;
 	nameForCondition?: string;
@@ -0,0 +1,40 @@
+import type * as t from "../../src/config/types";
+import type { RspackOptions } from "../../src/config/zod";
+
+// the callback of an externals function is called with `null` on success, as in webpack
+const callbackExternal: t.ExternalItem = ({ request }, callback) => {
+	if (request === "path") {
+		return callback(null, "commonjs path");
+	}
+	callback();
+};
+
+// an async externals function resolves to `undefined` to bundle the request
+const promiseExternal: t.ExternalItem = async ({ request }) => {
+	if (request === "fs") {
+		return undefined;
+	}
+	return "commonjs fs";
+};
+
+const invalidCallbackExternal: t.ExternalItem = (_, callback) => {
+	// @ts-expect-error the result is not an external item value
+	callback(null, 1);
+};
+
+const externals: t.Externals = [callbackExternal, promiseExternal];
+
+// the options inferred from the schema accept the same externals
+export const options: RspackOptions = {
+	externals: [
+		({ request }, callback) => {
+			if (request === "path") {
+				return callback(null, "commonjs path");
+			}
+			callback();
+		},
+		async ({ request }) => (request === "fs" ? undefined : "commonjs fs")
+	]
+};
+
+export { externals, invalidCallbackExternal };